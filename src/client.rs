@@ -1,27 +1,131 @@
 use std::collections::HashMap;
-
-use log::debug;
-use reqwest::header;
-pub use reqwest::Error;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use futures_util::{stream, Stream};
+use log::{debug, warn};
+use reqwest::{header, StatusCode};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::serde_as;
 use time::format_description::FormatItem;
 use time::macros::format_description;
 use time::Date;
 
+use crate::drift;
+use crate::drift::{DriftReport, SchemaCheck};
+use crate::endpoint::{CachePolicy, Endpoint, Get, Plan};
+pub use crate::error::Error;
 use crate::model::apimodels::*;
+use crate::model::categories::{Category, CategoryListing};
 use crate::model::coins::CoinsItem;
-use crate::model::common::{Ping, Price};
+use crate::model::common::{ExactAmount, Ping, Price};
 use crate::model::exchangerates::ExchangeRates;
+use crate::model::exchanges::{Exchange, ExchangeDetail, ExchangeListing};
 use crate::model::global::GlobalData;
+use crate::model::identifiers::{AssetPlatformId, ExchangeId};
 use crate::model::queryparams::*;
 use crate::model::simple::{CoinListing, ContractAddress};
+use crate::model::timestamp::{backend, DateTime};
 
 pub const COINGECKO_DATE_FORMAT: &[FormatItem<'_>] = format_description!("[day]-[month]-[year]");
 
 pub struct GeckoClient {
     client: reqwest::Client,
     api_url: String,
+    schema_check: SchemaCheck,
+    drift_reports: Mutex<HashMap<String, DriftReport>>,
+    plan: Plan,
+    cache: Mutex<HashMap<String, CachedResponse>>,
+    cache_ttl: Option<Duration>,
+    rate_limit: Option<RateLimit>,
+    retry: Retry,
+}
+
+/// How often and how long the client waits before it repeats a request that was answered with 429 or 5XX
+#[derive(Debug, Clone, Copy)]
+struct Retry {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Retry {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Retry {
+    /// Exponential backoff, doubling the base delay with every attempt up to one minute
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(Duration::from_secs(60))
+    }
+}
+
+/// Spaces the requests of a client evenly, the slot of the next request is reserved before waiting
+struct RateLimit {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimit {
+    fn new(interval: Duration) -> Self {
+        RateLimit {
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn per_minute(calls_per_minute: u32) -> Self {
+        RateLimit::new(Duration::from_secs(60) / calls_per_minute.max(1))
+    }
+
+    async fn wait(&self) {
+        let now = Instant::now();
+        let slot = {
+            let mut next_slot = self
+                .next_slot
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let slot = (*next_slot).max(now);
+            *next_slot = slot + self.interval;
+            slot
+        };
+
+        if slot > now {
+            tokio::time::sleep(slot - now).await;
+        }
+    }
+}
+
+/// Prices keyed by coin id or contract address, with every amount read through [ExactAmount]
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(
+    transparent,
+    bound(
+        serialize = "K: Serialize + Eq + Hash",
+        deserialize = "K: Deserialize<'de> + Eq + Hash"
+    )
+)]
+struct Prices<K>(#[serde_as(as = "HashMap<_, HashMap<_, ExactAmount>>")] HashMap<K, Price>);
+
+/// Number of responses the client keeps, the oldest is evicted to make room for a new one
+const CACHE_CAPACITY: usize = 256;
+
+struct CachedResponse {
+    /// Insertion order, the lowest is the oldest response
+    sequence: u64,
+    expires_at: Instant,
+    content: String,
 }
 
 impl Default for GeckoClient {
@@ -71,7 +175,14 @@ impl GeckoClient {
 
         GeckoClient {
             client: cl,
+            plan: plan_of(&api_url),
             api_url,
+            schema_check: SchemaCheck::default(),
+            drift_reports: Mutex::default(),
+            cache: Mutex::default(),
+            cache_ttl: None,
+            rate_limit: None,
+            retry: Retry::default(),
         }
     }
 
@@ -104,56 +215,388 @@ impl GeckoClient {
         client: reqwest::Client,
         api_url: impl Into<String>,
     ) -> GeckoClient {
+        let api_url = api_url.into();
+
         GeckoClient {
             client,
-            api_url: api_url.into(),
+            plan: plan_of(&api_url),
+            api_url,
+            schema_check: SchemaCheck::default(),
+            drift_reports: Mutex::default(),
+            cache: Mutex::default(),
+            cache_ttl: None,
+            rate_limit: None,
+            retry: Retry::default(),
+        }
+    }
+
+    /// Sets how responses are compared to the models, by default they are not compared at all.
+    /// With [SchemaCheck::Strict] every response that differs from its model fails with [Error::SchemaDrift]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::drift::SchemaCheck;
+    /// let client = GeckoClient::default().with_schema_check(SchemaCheck::Report);
+    /// ```
+    pub fn with_schema_check(mut self, schema_check: SchemaCheck) -> GeckoClient {
+        self.schema_check = schema_check;
+        self
+    }
+
+    /// The drift found in the responses so far, one report per endpoint.
+    /// Always empty unless the client runs with [SchemaCheck::Report] or [SchemaCheck::Strict]
+    pub fn drift_reports(&self) -> Vec<DriftReport> {
+        self.drift_reports
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Sets the Coingecko plan of the api key. It is detected from the api url by default,
+    /// set it if you reach the pro api through a proxy
+    pub fn with_plan(mut self, plan: Plan) -> GeckoClient {
+        self.plan = plan;
+        self
+    }
+
+    /// The Coingecko plan the client calls the API with
+    pub fn plan(&self) -> Plan {
+        self.plan
+    }
+
+    /// Limits the client to the given number of requests per minute, by default requests are sent right away.
+    /// Requests above the limit wait for their turn, responses served from the cache do not count.
+    /// The public API allows around 30 calls per minute, the paid plans between 500 and 1000, see [Plan::calls_per_minute].
+    /// Methods that split a range into several requests use the limit of the plan when none is set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// let client = GeckoClient::default().with_rate_limit(30);
+    /// ```
+    pub fn with_rate_limit(mut self, calls_per_minute: u32) -> GeckoClient {
+        self.rate_limit = Some(RateLimit::per_minute(calls_per_minute));
+        self
+    }
+
+    /// Sets how often a request answered with 429 (rate limited) or a 5XX status is repeated.
+    /// The client waits as long as the Retry-After header asks, otherwise `base_delay` doubled with every attempt.
+    /// By default a request is repeated up to 3 times starting with a delay of one second, 0 turns retries off
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rustgecko::client::GeckoClient;
+    /// let client = GeckoClient::default().with_retry(5, Duration::from_secs(2));
+    /// ```
+    pub fn with_retry(mut self, max_retries: u32, base_delay: Duration) -> GeckoClient {
+        self.retry = Retry {
+            max_retries,
+            base_delay,
+        };
+        self
+    }
+
+    /// Reuses the responses of the built-in methods, [GeckoClient::get_json] and [GeckoClient::get_typed] for identical
+    /// requests until they are older than `ttl`. By default they are always sent, endpoints passed to
+    /// [GeckoClient::execute] keep their own [Endpoint::cache_policy]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rustgecko::client::GeckoClient;
+    /// let client = GeckoClient::default().with_cache_ttl(Duration::from_secs(60));
+    /// ```
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> GeckoClient {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Removes all cached responses
+    pub fn clear_cache(&self) {
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Sends the request of an endpoint and deserializes the response into its model
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::CoinsMarketsRequest;
+    /// async fn top_ten() {
+    ///     let request = CoinsMarketsRequest::new("usd").per_page(10);
+    ///     let markets = GeckoClient::default().execute(&request).await;
+    /// }
+    /// ```
+    pub async fn execute<E: Endpoint>(&self, endpoint: &E) -> Result<E::Response, Error> {
+        let path = endpoint.path();
+
+        if endpoint.plan() > self.plan {
+            return Err(Error::PlanRequired {
+                endpoint: path,
+                plan: endpoint.plan(),
+            });
         }
+
+        let request = self.build_request(endpoint)?;
+        let content = match endpoint.cache_policy() {
+            CachePolicy::NoCache => self.fetch(&path, request).await?,
+            CachePolicy::Ttl(ttl) => {
+                let key = cache_key(&request);
+                match self.cached(&key) {
+                    Some(content) => content,
+                    None => {
+                        let content = self.fetch(&path, request).await?;
+                        self.store(key, content.clone(), ttl);
+                        content
+                    }
+                }
+            }
+        };
+
+        self.deserialize_response(&path, &content)
+    }
+
+    /// Calls any endpoint of the API and returns the raw JSON response,
+    /// for endpoints and fields this crate does not model yet.
+    /// The request goes through the same client, headers, rate limit, retries, cache (see [GeckoClient::with_cache_ttl])
+    /// and error handling as the built-in endpoints.
+    ///
+    /// # Arguments
+    /// * `path` - the path of the endpoint relative to the api url, eg. /coins/bitcoin/tickers
+    /// * `params` - the query parameters of the request
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// async fn trending() {
+    ///     let client = GeckoClient::default();
+    ///     let trending = client.get_json("/search/trending", &[]).await.unwrap();
+    ///     println!("{}", trending["coins"][0]["item"]["name"]);
+    /// }
+    /// ```
+    pub async fn get_json(&self, path: &str, params: &[(&str, &str)]) -> Result<Value, Error> {
+        self.get_typed(path, params).await
     }
 
-    async fn send_gecko_request<T: Serialize + ?Sized, D: DeserializeOwned>(
+    /// Calls any endpoint of the API and deserializes the response into your own model.
+    /// Like the built-in models it has to implement Serialize so it can be checked for drift, see [GeckoClient::with_schema_check]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use rustgecko::client::GeckoClient;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Categories(Vec<Category>);
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Category {
+    ///     category_id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// async fn categories() {
+    ///     let client = GeckoClient::default();
+    ///     let categories: Categories = client.get_typed("/coins/categories/list", &[]).await.unwrap();
+    /// }
+    /// ```
+    pub async fn get_typed<T: DeserializeOwned + Serialize>(
         &self,
-        endpoint: &str,
-        query_params: Option<&T>,
-    ) -> Result<D, reqwest::Error> {
-        let url = format!("{}{}", self.api_url, endpoint);
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, Error> {
+        self.execute(&self.get(path).params(params)?).await
+    }
+
+    fn build_request<E: Endpoint>(&self, endpoint: &E) -> Result<reqwest::Request, reqwest::Error> {
+        let url = format!("{}{}", self.api_url, endpoint.path());
+        let query = endpoint.query();
+
+        let mut req_builder = self.client.request(endpoint.method(), url);
+
+        if !query.is_empty() {
+            req_builder = req_builder.query(&query);
+        }
 
-        let mut req_builder = self.client.get(url);
+        req_builder.build()
+    }
+
+    /// GET request to a path, cached like every built-in method, see [GeckoClient::with_cache_ttl]
+    fn get<D>(&self, path: impl Into<String>) -> Get<D> {
+        Get::new(path).cache(
+            self.cache_ttl
+                .map_or(CachePolicy::NoCache, CachePolicy::Ttl),
+        )
+    }
+
+    async fn send_gecko_request<K: AsRef<str>, V: Serialize, D: DeserializeOwned + Serialize>(
+        &self,
+        endpoint: &str,
+        query_params: Option<&[(K, V)]>,
+    ) -> Result<D, Error> {
+        let mut request = self.get(endpoint);
 
         if let Some(params) = query_params {
-            req_builder = req_builder.query(params);
+            request = request.params(params)?;
         }
 
-        let request = req_builder.build()?;
-        debug!("Calling CoinGecko API with url: {}", request.url());
-        let response = self.client.execute(request).await?;
+        self.execute(&request).await
+    }
+
+    async fn fetch(&self, path: &str, mut request: reqwest::Request) -> Result<String, Error> {
+        let mut attempt = 0;
+
+        let response = loop {
+            let retry = request
+                .try_clone()
+                .filter(|_| attempt < self.retry.max_retries);
+
+            if let Some(rate_limit) = &self.rate_limit {
+                rate_limit.wait().await;
+            }
+
+            debug!("Calling CoinGecko API with url: {}", request.url());
+            let response = self.client.execute(request).await?;
+            let status = response.status();
+
+            match retry {
+                Some(retry) if is_retryable(status) => {
+                    let delay = retry_after(&response).unwrap_or(self.retry.backoff(attempt));
+                    warn!("{} answered with {}, retrying in {:?}", path, status, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    request = retry;
+                }
+                _ => break response,
+            }
+        };
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(Error::NotFound {
+                endpoint: path.to_string(),
+                message: not_found_message(&response.text().await?),
+            });
+        }
 
         //Handle 4XX Status Codes
         if let Err(error) = response.error_for_status_ref() {
             debug!("{}", response.text().await?);
-            return Err(error);
+            return Err(error.into());
         };
 
-        let content = response.text().await?;
-        //info!("Response as text : {}", &content);
-        let jd = &mut serde_json::Deserializer::from_str(&content);
+        Ok(response.text().await?)
+    }
+
+    /// Limit for the requests of a method that splits a range, None if the client already limits every request
+    fn series_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+            .is_none()
+            .then(|| RateLimit::per_minute(self.plan.calls_per_minute()))
+    }
+
+    fn cached(&self, key: &str) -> Option<String> {
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match cache.get(key) {
+            Some(cached) if cached.expires_at > Instant::now() => Some(cached.content.clone()),
+            Some(_) => {
+                cache.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
 
-        let result: Result<D, _> = serde_path_to_error::deserialize(jd);
+    /// Drops the expired responses and, when the cache is full, the oldest one before inserting
+    fn store(&self, key: String, content: String, ttl: Duration) {
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
 
-        match result {
-            Ok(res) => Ok(res),
-            Err(err) => {
-                panic!(
-                    "Failed to Deserialize response from Coingecko into Json with Error : {}",
-                    err.to_string()
-                );
+        cache.retain(|_, cached| cached.expires_at > now);
+        if cache.len() >= CACHE_CAPACITY && !cache.contains_key(&key) {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, cached)| cached.sequence)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
             }
         }
-        //response.json().await
+
+        let sequence = cache
+            .values()
+            .map(|cached| cached.sequence + 1)
+            .max()
+            .unwrap_or_default();
+        cache.insert(
+            key,
+            CachedResponse {
+                sequence,
+                expires_at: now + ttl,
+                content,
+            },
+        );
+    }
+
+    fn deserialize_response<D: DeserializeOwned + Serialize>(
+        &self,
+        endpoint: &str,
+        content: &str,
+    ) -> Result<D, Error> {
+        let into_error = |source| Error::Deserialize {
+            endpoint: endpoint.to_string(),
+            source,
+        };
+        let jd = &mut serde_json::Deserializer::from_str(content);
+
+        if self.schema_check == SchemaCheck::Off {
+            return serde_path_to_error::deserialize(jd).map_err(into_error);
+        }
+
+        let value: Value = serde_path_to_error::deserialize(jd).map_err(into_error)?;
+        let (result, report) = drift::check::<D>(endpoint, &value);
+
+        if !report.is_empty() {
+            warn!("{}", report);
+            self.drift_reports
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(endpoint.to_string())
+                .or_insert_with(|| DriftReport {
+                    endpoint: endpoint.to_string(),
+                    ..DriftReport::default()
+                })
+                .merge(report.clone());
+
+            if self.schema_check == SchemaCheck::Strict {
+                return Err(Error::SchemaDrift(report));
+            }
+        }
+
+        // the value holds the numbers as f64, the model is read from the text again to keep every digit of its amounts
+        result.map_err(into_error)?;
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(content))
+            .map_err(into_error)
     }
 
     /// Check API server status
-    pub async fn ping(&self) -> Result<Ping, reqwest::Error> {
-        self.send_gecko_request("/ping", None::<&[()]>).await
+    pub async fn ping(&self) -> Result<Ping, Error> {
+        self.send_gecko_request("/ping", None::<&[(&str, &str)]>)
+            .await
     }
 
     /// Calls the simple/supported_vs_currencies endpoint
@@ -165,8 +608,8 @@ impl GeckoClient {
     /// let client = GeckoClient::new("https://some.url");
     /// client.simple_supportedvscurrencies();
     /// ```
-    pub async fn simple_supportedvscurrencies(&self) -> Result<Vec<String>, reqwest::Error> {
-        self.send_gecko_request("/simple/supported_vs_currencies", None::<&[()]>)
+    pub async fn simple_supportedvscurrencies(&self) -> Result<Vec<String>, Error> {
+        self.send_gecko_request("/simple/supported_vs_currencies", None::<&[(&str, &str)]>)
             .await
     }
 
@@ -175,7 +618,7 @@ impl GeckoClient {
         &self,
         ids: &[&str],
         vs_currencies: &[&str],
-    ) -> Result<HashMap<String, Price>, reqwest::Error> {
+    ) -> Result<HashMap<String, Price>, Error> {
         self.simple_price(ids, vs_currencies, true, true, true, true, "max")
             .await
     }
@@ -200,7 +643,7 @@ impl GeckoClient {
         include_24hr_change: bool,
         include_last_updated_at: bool,
         precision: &str,
-    ) -> Result<HashMap<String, Price>, reqwest::Error> {
+    ) -> Result<HashMap<String, Price>, Error> {
         self.send_gecko_request(
             "/simple/price",
             Some(&[
@@ -217,6 +660,7 @@ impl GeckoClient {
             ]),
         )
         .await
+        .map(|Prices(prices)| prices)
     }
     /// Calls the simple/price/{id} endpoint
     /// The supported Currencies can be retrieved via the simple/supported_vs_currencies endpoint
@@ -239,7 +683,7 @@ impl GeckoClient {
         include_24hr_change: bool,
         include_last_updated_at: bool,
         precision: &str,
-    ) -> Result<HashMap<String, Price>, reqwest::Error> {
+    ) -> Result<HashMap<String, Price>, Error> {
         self.send_gecko_request(
             "/simple/price",
             Some(&[
@@ -257,14 +701,15 @@ impl GeckoClient {
             ]),
         )
         .await
+        .map(|Prices(prices)| prices)
     }
 
     pub async fn simple_token_price_short(
         &self,
-        id: &str,
+        id: &AssetPlatformId,
         vs_currencies: &[&str],
-        contract_addresses: &[&str],
-    ) -> Result<HashMap<ContractAddress, Price>, reqwest::Error> {
+        contract_addresses: &[ContractAddress],
+    ) -> Result<HashMap<ContractAddress, Price>, Error> {
         self.simple_token_price(
             id,
             vs_currencies,
@@ -284,15 +729,18 @@ impl GeckoClient {
     ///
     /// ```rust
     /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::identifiers::AssetPlatformId;
     /// let client = GeckoClient::default();
+    /// let platform = AssetPlatformId::new("ethereum");
+    /// let contract_addresses = [
+    ///     "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984".into(),
+    ///     "0xf629cbd94d3791c9250152bd8dfbdf380e2a3b9c".into(),
+    /// ];
     /// let res = client
     ///             .simple_token_price(
-    ///                 "ethereum",
+    ///                 &platform,
     ///                 &["usd", "eur"],
-    ///                 &[
-    ///                     "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984",
-    ///                     "0xf629cbd94d3791c9250152bd8dfbdf380e2a3b9c",
-    ///                 ],
+    ///                 &contract_addresses,
     ///                 false,
     ///                 false,
     ///                 false,
@@ -303,15 +751,15 @@ impl GeckoClient {
     #[allow(clippy::too_many_arguments)]
     pub async fn simple_token_price(
         &self,
-        id: &str,
+        id: &AssetPlatformId,
         vs_currencies: &[&str],
-        contract_addresses: &[&str],
+        contract_addresses: &[ContractAddress],
         include_market_cap: bool,
         include_24hr_vol: bool,
         include_24hr_change: bool,
         include_last_updated_at: bool,
         precision: &str,
-    ) -> Result<HashMap<ContractAddress, Price>, reqwest::Error> {
+    ) -> Result<HashMap<ContractAddress, Price>, Error> {
         let url = format!("/simple/token_price/{}", id);
         self.send_gecko_request(
             &url,
@@ -325,63 +773,55 @@ impl GeckoClient {
                     include_last_updated_at.to_string(),
                 ),
                 ("precision", precision.to_string()),
-                ("contract_addresses", contract_addresses.join(",")),
+                (
+                    "contract_addresses",
+                    contract_addresses
+                        .iter()
+                        .map(ContractAddress::as_str)
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
             ]),
         )
         .await
+        .map(|Prices(prices)| prices)
     }
 
     ///Use this to obtain all the coins' id in order to make API calls
-    pub async fn coins_list(&self) -> Result<Vec<CoinListing>, reqwest::Error> {
+    pub async fn coins_list(&self) -> Result<Vec<CoinListing>, Error> {
         self.send_gecko_request("/coins/list", Some(&[("include_platform", "true")]))
             .await
     }
 
     /// Use this to obtain all the coins market data (price, market cap, volume)
     ///
-    /// # Arguments
-    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
-    /// * `ids` - The ids of the coin, comma separated crytocurrency symbols (base). refers to /coins/list.
-    /// * `ordering` - sort results by field. Default value if None is passed: market_cap_desc
-    /// * `price_change_percentage ` - Include price change percentage for the given TimeFrames
-    /// * `sparkline `- Include sparkline 7 days data (eg. true, false)
-    /// * `page `- Page through results. If None default value 1 is used
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::{CoinsMarketsRequest, MarketOrder, PriceChange};
+    /// let client = GeckoClient::default();
+    /// let request = CoinsMarketsRequest::new("usd")
+    ///     .order(MarketOrder::MarketCapDesc)
+    ///     .per_page(250)
+    ///     .price_change_percentage(&[PriceChange::Days7, PriceChange::Years1]);
+    /// client.coins_markets(&request);
+    /// ```
     pub async fn coins_markets(
         &self,
-        vs_currency: &str,
-        ids: Option<&[&str]>,
-        ordering: MarketOrder,
-        price_change_percentage: Option<&[PriceChange]>,
-        sparkline: bool,
-        page: Option<i64>,
-    ) -> Result<Vec<CoinsMarketItem>, reqwest::Error> {
-        let mut params: Vec<(&str, String)> = Vec::new();
-
-        params.push(("vs_currency", vs_currency.into()));
-        params.push(("order", ordering.to_string()));
-        params.push(("sparkline", sparkline.to_string()));
-
-        if let Some(ids) = ids {
-            params.push(("ids", ids.join("%2C")))
+        request: &CoinsMarketsRequest,
+    ) -> Result<Vec<CoinsMarketItem>, Error> {
+        if let Some(precision) = request.precision {
+            precision.check(&request.path())?;
         }
-        if let Some(price_change) = price_change_percentage {
-            let tmp = price_change
-                .iter()
-                .map(|ele| ele.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            params.push(("price_change", tmp));
-        }
-        if let Some(page) = page {
-            params.push(("page", page.to_string()));
-        }
-        self.send_gecko_request("/coins/markets", Some(&params))
-            .await
+
+        self.execute(request).await
     }
 
     /// Shortcut Method that calls [GeckoClient::coins] with all Flags set to true for convenience
-    pub async fn coins_short(&self, id: &str) -> Result<CoinsItem, reqwest::Error> {
-        self.coins(id, true, true, true, true, true, true).await
+    pub async fn coins_short(&self, id: &str) -> Result<CoinsItem, Error> {
+        self.coins(id, Localize::All, true, true, true, true, true)
+            .await
     }
 
     /// Get current data (name, price, market, ... including exchange tickers) for a coin.
@@ -391,50 +831,100 @@ impl GeckoClient {
     /// Ticker is_stale is true when ticker that has not been updated/unchanged from the exchange for a while.
     /// Ticker is_anomaly is true if ticker's price is outliered by our system.
     /// You are responsible for managing how you want to display these information (e.g. footnote, different background, change opacity, hide)
+    ///
+    /// `localization` selects the languages of the localized names and descriptions, see [Localize]
     #[allow(clippy::too_many_arguments)]
     pub async fn coins(
         &self,
         id: &str,
-        localization: bool,
+        localization: Localize,
         tickers: bool,
         market_data: bool,
         community_data: bool,
         developer_data: bool,
         sparkline: bool,
-    ) -> Result<CoinsItem, reqwest::Error> {
+    ) -> Result<CoinsItem, Error> {
         let url = format!("/coins/{}", id);
         let params = [
-            ("localization", localization),
-            ("tickers", tickers),
-            ("market_data", market_data),
-            ("community_data", community_data),
-            ("developer_data", developer_data),
-            ("sparkline", sparkline),
+            ("localization", localization.to_string()),
+            ("tickers", tickers.to_string()),
+            ("market_data", market_data.to_string()),
+            ("community_data", community_data.to_string()),
+            ("developer_data", developer_data.to_string()),
+            ("sparkline", sparkline.to_string()),
         ];
-        self.send_gecko_request(&url, Some(&params)).await
+
+        let mut coin: CoinsItem = self.send_gecko_request(&url, Some(&params)).await?;
+        coin.retain_languages(&localization);
+        Ok(coin)
     }
 
-    async fn coins_tickers(
-        &self,
-        id: &str,
-        exchange_ids: Option<&[&str]>,
-        include_exchange_logo: bool,
-        page: i64,
-        order: TrustOrder,
-    ) -> Result<Vec<Ticker>, reqwest::Error> {
-        let url = &format!("/coins/{}/tickers", id);
-
-        let mut params: Vec<(&str, String)> = vec![
-            ("include_exchange_logo", include_exchange_logo.to_string()),
-            ("page", page.to_string()),
-            ("order", order.to_string()),
-        ];
+    /// Get one page of the tickers of a coin, 100 tickers per page.
+    ///
+    /// Use [GeckoClient::coins_tickers_pages] to walk all pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::{CoinTickersRequest, TickerOrder};
+    ///
+    /// let client = GeckoClient::default();
+    /// let request = CoinTickersRequest::new("bitcoin")
+    ///     .exchange_ids(["binance"])
+    ///     .order(TickerOrder::VolumeDesc)
+    ///     .depth(true);
+    ///
+    /// client.coins_tickers(&request);
+    /// ```
+    pub async fn coins_tickers(&self, request: &CoinTickersRequest) -> Result<Tickers, Error> {
+        self.execute(request).await
+    }
 
-        if let Some(ids) = exchange_ids {
-            params.push(("exchange_ids", ids.join("%2C")))
-        }
+    /// Stream of all ticker pages of a coin, starting at the page of the request.
+    ///
+    /// The next page is only requested once the previous one was consumed. The stream ends after
+    /// the first page with less than [Paged::PAGE_SIZE] tickers or after an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_util::TryStreamExt;
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::CoinTickersRequest;
+    ///
+    /// async fn all_tickers() {
+    ///     let client = GeckoClient::default();
+    ///     let pages = client.coins_tickers_pages(&CoinTickersRequest::new("bitcoin"));
+    ///     let tickers: Vec<_> = pages
+    ///         .map_ok(|page| page.tickers)
+    ///         .try_concat()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn coins_tickers_pages<'a>(
+        &'a self,
+        request: &CoinTickersRequest,
+    ) -> impl Stream<Item = Result<Tickers, Error>> + 'a {
+        self.ticker_pages(request.clone())
+    }
 
-        self.send_gecko_request(url, Some(&params)).await
+    /// Requests pages until one has less than [Paged::PAGE_SIZE] tickers, or a request fails
+    fn ticker_pages<'a, E>(&'a self, first: E) -> impl Stream<Item = Result<Tickers, Error>> + 'a
+    where
+        E: Paged<Response = Tickers> + 'a,
+    {
+        stream::unfold(Some(first), move |next| async move {
+            let request = next?;
+            match self.execute(&request).await {
+                Ok(page) => {
+                    let next = (page.tickers.len() >= E::PAGE_SIZE).then(|| request.next_page());
+                    Some((Ok(page), next))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        })
     }
 
     ///Get historical data (name, price, market, stats) at a given date for a coin
@@ -442,105 +932,351 @@ impl GeckoClient {
     ///# Arguments
     /// * `id` - pass the coin id (can be obtained from /coins) eg. bitcoin
     /// * `date` - The date of data snapshot, the function takes care of the proper Date Formatting to dd-mm-yyyy eg. 30-12-2017
-    /// * `localization` - Set to false to exclude localized languages in response
+    /// * `localization` - The languages of the localized names, see [Localize]
     pub async fn coins_history(
         &self,
         id: &str,
         date: Date,
-        localization: Option<&str>,
-    ) -> Result<CoinHistoryItem, reqwest::Error> {
+        localization: Localize,
+    ) -> Result<CoinHistoryItem, Error> {
         let date = date.format(COINGECKO_DATE_FORMAT).unwrap();
-        let mut params: Vec<(&str, &str)> = vec![("date", &date)];
+        let params = [("date", date), ("localization", localization.to_string())];
 
-        if let Some(languages) = localization {
-            params.push(("localization", languages));
-        }
+        let mut history: CoinHistoryItem = self
+            .send_gecko_request(&format!("/coins/{}/history", id), Some(&params))
+            .await?;
+        history.retain_languages(&localization);
+        Ok(history)
+    }
 
-        self.send_gecko_request(&format!("/coins/{}/history", id), Some(&params))
+    ///Get historical market data include price, market cap, and 24h volume
+    ///
+    /// Without an interval the granularity follows from the days: 5 minutes for 1 day,
+    /// hourly up to 90 days and daily above. [MarketChart::granularity] tells which one the API returned.
+    ///
+    /// # Arguments
+    /// * `id` - pass the coin id (can be obtained from /coins) eg. bitcoin
+    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
+    /// * `days` - Data up to number of days ago
+    /// * `interval` - Data interval, hourly and 5 minutes are only available with a paid plan
+    /// * `precision` - Decimal places of the price values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::{ChartDays, Interval};
+    /// async fn last_month() {
+    ///     let client = GeckoClient::default();
+    ///     let chart = client
+    ///         .coins_marketchart("bitcoin", "usd", ChartDays::Days(30), Some(Interval::Daily), None)
+    ///         .await;
+    /// }
+    /// ```
+    pub async fn coins_marketchart(
+        &self,
+        id: &str,
+        vs_currency: &str,
+        days: ChartDays,
+        interval: Option<Interval>,
+        precision: Option<Precision>,
+    ) -> Result<MarketChart, Error> {
+        let url = format!("/coins/{}/market_chart", id);
+        self.marketchart(&url, vs_currency, days, interval, precision)
             .await
     }
 
-    ///Get historical market data include price, market cap, and 24h volume (granularity auto)
+    ///Get historical market data include price, market cap, and 24h volume between two points in time
+    ///
+    /// Coingecko picks the granularity from the length of the range, see [Granularity].
+    /// The range is split into windows that yield the requested granularity, the windows are fetched one after
+    /// another and merged into one chart. They are sent under the rate limit of the client (see [GeckoClient::with_rate_limit]),
+    /// a client without one spaces them by the [Plan::calls_per_minute] of its plan.
+    ///
+    /// Every window is a request: five minute data comes in windows of one day and hourly data in windows of 90 days.
+    /// Ranges that need more than 100 requests are rejected with [Error::InvalidParams] before anything is sent,
+    /// fetch longer ranges with a coarser granularity.
     ///
     /// # Arguments
     /// * `id` - pass the coin id (can be obtained from /coins) eg. bitcoin
-    /// * `vs_currencies` - The target currency of market data (usd, eur, jpy, etc.)
-    /// * `days` - Data up to number of days ago (eg. 1,14,30,max)
-    /// * `interval` - Data interval. Possible value: daily
-    pub(crate) async fn coins_marketchart(
+    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
+    /// * `from` - Start of the range
+    /// * `to` - End of the range
+    /// * `granularity` - Spacing of the data points
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::Granularity;
+    /// use rustgecko::model::timestamp::DateTime;
+    ///
+    /// async fn hourly(from: DateTime, to: DateTime) {
+    ///     let client = GeckoClient::default().with_rate_limit(30);
+    ///     let chart = client
+    ///         .coins_marketchart_range("bitcoin", "usd", from, to, Granularity::Hourly)
+    ///         .await;
+    /// }
+    /// ```
+    pub async fn coins_marketchart_range(
         &self,
         id: &str,
-        vs_currencies: &str,
-        days: &str,
-        interval: Option<&str>,
-    ) -> Result<MarketChart, reqwest::Error> {
-        let url = format!("/coins/{}/market_chart", id);
-        let mut params = vec![("vs_currency", vs_currencies), ("days", days)];
+        vs_currency: &str,
+        from: DateTime,
+        to: DateTime,
+        granularity: Granularity,
+    ) -> Result<MarketChart, Error> {
+        let url = format!("/coins/{}/market_chart/range", id);
+        self.marketchart_range(&url, vs_currency, from, to, granularity)
+            .await
+    }
 
-        if let Some(interval) = interval {
-            params.push(("interval", interval));
+    async fn marketchart(
+        &self,
+        url: &str,
+        vs_currency: &str,
+        days: ChartDays,
+        interval: Option<Interval>,
+        precision: Option<Precision>,
+    ) -> Result<MarketChart, Error> {
+        if let Some(precision) = precision {
+            precision.check(url)?;
         }
 
-        self.send_gecko_request(&url, Some(&params)).await
+        let plan = interval.map_or(Plan::Public, |interval| interval.plan());
+        let request = self
+            .get(url)
+            .params(&marketchart_params(vs_currency, days, interval, precision))?
+            .requires(plan);
+
+        self.execute(&request).await
     }
 
-    //TODO: test this endpoint
-    async fn coins_marketchart_range(
+    async fn marketchart_range(
         &self,
-        id: impl Into<String>,
-        vs_currency: impl Into<String>,
-        from: impl Into<String>,
-        to: impl Into<String>,
-    ) -> Result<MarketChart, reqwest::Error> {
-        let url = format!("/coins/{}/market_chart/range", id.into());
-        let params = vec![
-            ("vs_currency", vs_currency.into()),
-            ("from", from.into()),
-            ("to", to.into()),
-        ];
-        self.send_gecko_request(&url, Some(&params)).await
+        url: &str,
+        vs_currency: &str,
+        from: DateTime,
+        to: DateTime,
+        granularity: Granularity,
+    ) -> Result<MarketChart, Error> {
+        let (from, to) = (backend::unix_seconds(&from), backend::unix_seconds(&to));
+        let windows = chart_windows(from, to, granularity);
+
+        check_window_count(url, windows.len(), granularity)?;
+
+        let limit = self.series_limit();
+        let mut chunks = Vec::with_capacity(windows.len());
+        for (start, end) in windows {
+            if let Some(limit) = &limit {
+                limit.wait().await;
+            }
+            let params = [
+                ("vs_currency", vs_currency.to_string()),
+                ("from", start.to_string()),
+                ("to", end.to_string()),
+            ];
+            chunks.push(self.send_gecko_request(url, Some(&params)).await?);
+        }
+
+        Ok(merge_market_charts(chunks, from * 1000, to * 1000))
     }
 
+    ///Get coin's OHLC, the time of a candle marks its close
     ///
     /// # Arguments
     /// * `id` - pass the coin id (can be obtained from /coins/list) eg. bitcoin
     /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
     /// * `days` - Data up to number of days ago (1/7/14/30/90/180/365/max)
+    /// * `interval` - Candle size, only available with a paid plan. Leave it None to get the automatic size
     ///
-    async fn coins_ohlc(
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::OhlcDays;
+    /// async fn last_week() {
+    ///     let client = GeckoClient::default();
+    ///     let candles = client.coins_ohlc("bitcoin", "usd", OhlcDays::SevenDays, None).await;
+    /// }
+    /// ```
+    pub async fn coins_ohlc(
         &self,
         id: &str,
         vs_currency: &str,
-        days: &str,
-    ) -> Result<Vec<Candle>, reqwest::Error> {
-        let url = format!("/coins/{}/ohlc", id);
-        let params = vec![("vs_currency", vs_currency), ("days", days)];
+        days: OhlcDays,
+        interval: Option<OhlcInterval>,
+    ) -> Result<Vec<Candle>, Error> {
+        let plan = match interval {
+            Some(_) => Plan::Pro,
+            None => Plan::Public,
+        };
+        let request = self
+            .get(format!("/coins/{}/ohlc", id))
+            .params(&ohlc_params(vs_currency, days, interval))?
+            .requires(plan);
 
-        self.send_gecko_request(&url, Some(&params)).await
+        self.execute(&request).await
     }
 
-    /*   pub fn contract(&self, id: &str, contract_address: &str) -> Result<_, reqwest::Error> {
-        let url = format!("/coins/{}/contract/{}", id, contract_address);
+    ///Get coin's OHLC between two points in time, only available with a paid plan
+    ///
+    /// The API limits the span of a single request (180 days of daily or 31 days of hourly candles),
+    /// longer ranges are split into several requests and the candles are returned in order without duplicates.
+    /// The requests are spaced like the ones of [GeckoClient::coins_marketchart_range], ranges that need more
+    /// than 100 of them are rejected with [Error::InvalidParams] before anything is sent.
+    ///
+    /// # Arguments
+    /// * `id` - pass the coin id (can be obtained from /coins/list) eg. bitcoin
+    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
+    /// * `from` - Start of the range
+    /// * `to` - End of the range
+    /// * `interval` - Candle size
+    pub async fn coins_ohlc_range(
+        &self,
+        id: &str,
+        vs_currency: &str,
+        from: DateTime,
+        to: DateTime,
+        interval: OhlcInterval,
+    ) -> Result<Vec<Candle>, Error> {
+        let url = format!("/coins/{}/ohlc/range", id);
+        let windows = time_windows(
+            backend::unix_seconds(&from),
+            backend::unix_seconds(&to),
+            interval.max_span(),
+        );
+        check_window_count(&url, windows.len(), interval)?;
+
+        let limit = self.series_limit();
+        let mut chunks = Vec::with_capacity(windows.len());
+        for (start, end) in windows {
+            if let Some(limit) = &limit {
+                limit.wait().await;
+            }
+            let request = self
+                .get(&url)
+                .params(&[
+                    ("vs_currency", vs_currency.to_string()),
+                    ("from", start.to_string()),
+                    ("to", end.to_string()),
+                    ("interval", interval.to_string()),
+                ])?
+                .requires(Plan::Pro);
+            chunks.push(self.execute(&request).await?);
+        }
+
+        Ok(merge_candles(chunks))
+    }
 
-        self.send_gecko_request(url, None)
-    }*/
+    /// Get coin info from contract address
+    ///
+    /// Fails with [Error::NotFound] if Coingecko does not know the address on the platform
+    ///
+    /// # Arguments
+    /// * `platform` - The asset platform of the contract (can be obtained from /asset_platforms) eg. ethereum
+    /// * `contract_address` - The address of the token contract
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::{Error, GeckoClient};
+    /// use rustgecko::model::identifiers::{AssetPlatformId, ContractAddress};
+    ///
+    /// async fn uniswap() {
+    ///     let client = GeckoClient::default();
+    ///     let address = ContractAddress::new("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984");
+    ///     match client.contract(&AssetPlatformId::new("ethereum"), &address).await {
+    ///         Ok(coin) => println!("{} is {}", address, coin.id),
+    ///         Err(Error::NotFound { .. }) => println!("{} is not listed", address),
+    ///         Err(err) => println!("{}", err),
+    ///     }
+    /// }
+    /// ```
+    pub async fn contract(
+        &self,
+        platform: &AssetPlatformId,
+        contract_address: &ContractAddress,
+    ) -> Result<Contract, Error> {
+        let url = format!("/coins/{}/contract/{}", platform, contract_address);
+        self.send_gecko_request(&url, None::<&[(&str, &str)]>).await
+    }
 
-    pub fn contract_marketchart() {
-        todo!();
+    /// Get historical market data include price, market cap, and 24h volume of a token from its contract address,
+    /// like [GeckoClient::coins_marketchart]
+    ///
+    /// # Arguments
+    /// * `platform` - The asset platform of the contract (can be obtained from /asset_platforms) eg. ethereum
+    /// * `contract_address` - The address of the token contract
+    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
+    /// * `days` - Data up to number of days ago
+    /// * `interval` - Data interval, hourly and 5 minutes are only available with a paid plan
+    /// * `precision` - Decimal places of the price values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::identifiers::{AssetPlatformId, ContractAddress};
+    /// use rustgecko::model::queryparams::ChartDays;
+    /// async fn uniswap_last_week() {
+    ///     let client = GeckoClient::default();
+    ///     let address = ContractAddress::new("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984");
+    ///     let chart = client
+    ///         .contract_marketchart(&AssetPlatformId::new("ethereum"), &address, "usd", ChartDays::Days(7), None, None)
+    ///         .await;
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn contract_marketchart(
+        &self,
+        platform: &AssetPlatformId,
+        contract_address: &ContractAddress,
+        vs_currency: &str,
+        days: ChartDays,
+        interval: Option<Interval>,
+        precision: Option<Precision>,
+    ) -> Result<MarketChart, Error> {
+        let url = format!(
+            "/coins/{}/contract/{}/market_chart",
+            platform, contract_address
+        );
+        self.marketchart(&url, vs_currency, days, interval, precision)
+            .await
     }
 
-    pub fn contract_marketchart_range() {
-        todo!();
+    /// Get historical market data include price, market cap, and 24h volume of a token from its contract address
+    /// between two points in time, like [GeckoClient::coins_marketchart_range]
+    ///
+    /// # Arguments
+    /// * `platform` - The asset platform of the contract (can be obtained from /asset_platforms) eg. ethereum
+    /// * `contract_address` - The address of the token contract
+    /// * `vs_currency` - The target currency of market data (usd, eur, jpy, etc.)
+    /// * `from` - Start of the range
+    /// * `to` - End of the range
+    /// * `granularity` - Spacing of the data points
+    pub async fn contract_marketchart_range(
+        &self,
+        platform: &AssetPlatformId,
+        contract_address: &ContractAddress,
+        vs_currency: &str,
+        from: DateTime,
+        to: DateTime,
+        granularity: Granularity,
+    ) -> Result<MarketChart, Error> {
+        let url = format!(
+            "/coins/{}/contract/{}/market_chart/range",
+            platform, contract_address
+        );
+        self.marketchart_range(&url, vs_currency, from, to, granularity)
+            .await
     }
 
     ///List all asset platforms
     ///
     /// # Arguments
     /// * `filter` - apply relevant filters to results. Valid values: "nft" (asset_platform nft-support)
-    pub(crate) async fn assetplatforms(
-        &self,
-        filter: Option<&str>,
-    ) -> Result<Vec<AssetPlatform>, reqwest::Error> {
+    pub async fn assetplatforms(&self, filter: Option<&str>) -> Result<Vec<AssetPlatform>, Error> {
         let mut params: Vec<(&str, &str)> = Vec::new();
 
         if let Some(value) = filter {
@@ -550,28 +1286,115 @@ impl GeckoClient {
         self.send_gecko_request("/asset_platforms", Some(&params))
             .await
     }
-    fn categories_list() {
-        todo!();
+
+    /// List all categories, their ids can be used to filter [GeckoClient::coins_markets]
+    pub async fn categories_list(&self) -> Result<Vec<CategoryListing>, Error> {
+        self.send_gecko_request("/coins/categories/list", None::<&[(&str, &str)]>)
+            .await
     }
 
-    fn categories() {
-        todo!();
+    /// List all categories with market data
+    ///
+    /// # Arguments
+    /// * `order` - Sort order of the categories. The API defaults to market_cap_desc
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::{CategoryOrder, CoinsMarketsRequest};
+    ///
+    /// async fn top_category_coins() {
+    ///     let client = GeckoClient::default();
+    ///     let categories = client.categories(Some(CategoryOrder::MarketCapChange24hDesc)).await.unwrap();
+    ///     let request = CoinsMarketsRequest::new("usd").category(categories[0].id.clone());
+    ///     let coins = client.coins_markets(&request).await;
+    /// }
+    /// ```
+    pub async fn categories(&self, order: Option<CategoryOrder>) -> Result<Vec<Category>, Error> {
+        self.send_gecko_request("/coins/categories", Some(&categories_params(order)))
+            .await
     }
 
-    fn all_exchanges() {
-        todo!();
+    /// List all active exchanges with their trust score and 24h volume, ranked by trust score
+    ///
+    /// # Arguments
+    /// * `per_page` - Total results per page, valid values are 1 - 250. The API defaults to 100
+    /// * `page` - Page through results. The API defaults to 1
+    pub async fn all_exchanges(
+        &self,
+        per_page: Option<u16>,
+        page: Option<u32>,
+    ) -> Result<Vec<Exchange>, Error> {
+        self.send_gecko_request("/exchanges", Some(&exchanges_params(per_page, page)))
+            .await
     }
 
-    fn exchanges_list() {
-        todo!();
+    /// Use this to obtain all the exchanges' id in order to make API calls
+    pub async fn exchanges_list(&self) -> Result<Vec<ExchangeListing>, Error> {
+        self.send_gecko_request("/exchanges/list", None::<&[(&str, &str)]>)
+            .await
     }
 
-    fn exchanges_tickers() {
-        todo!();
+    /// Get one page of the tickers of an exchange, 100 tickers per page.
+    ///
+    /// Use [GeckoClient::exchanges_tickers_pages] to walk all pages.
+    pub async fn exchanges_tickers(
+        &self,
+        request: &ExchangeTickersRequest,
+    ) -> Result<Tickers, Error> {
+        self.execute(request).await
     }
 
-    fn exchanges() {
-        todo!();
+    /// Stream of all ticker pages of an exchange, starting at the page of the request.
+    ///
+    /// The next page is only requested once the previous one was consumed. The stream ends after
+    /// the first page with less than [Paged::PAGE_SIZE] tickers or after an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures_util::TryStreamExt;
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::ExchangeTickersRequest;
+    ///
+    /// async fn all_markets() {
+    ///     let client = GeckoClient::default().with_rate_limit(30);
+    ///     let mut pages = Box::pin(client.exchanges_tickers_pages(&ExchangeTickersRequest::new("kraken")));
+    ///     while let Some(page) = pages.try_next().await.unwrap() {
+    ///         for ticker in page.tickers {
+    ///             println!("{}/{}", ticker.base, ticker.target);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn exchanges_tickers_pages<'a>(
+        &'a self,
+        request: &ExchangeTickersRequest,
+    ) -> impl Stream<Item = Result<Tickers, Error>> + 'a {
+        self.ticker_pages(request.clone())
+    }
+
+    /// Get exchange volume in BTC and the top 100 tickers of an exchange
+    ///
+    /// # Arguments
+    /// * `id` - pass the exchange id (can be obtained from /exchanges/list) eg. binance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::identifiers::ExchangeId;
+    /// async fn binance() {
+    ///     let binance = GeckoClient::default().exchanges(&ExchangeId::new("binance")).await.unwrap();
+    ///     for ticker in binance.tickers {
+    ///         println!("{}/{}: {}", ticker.base, ticker.target, ticker.last);
+    ///     }
+    /// }
+    /// ```
+    pub async fn exchanges(&self, id: &ExchangeId) -> Result<ExchangeDetail, Error> {
+        self.send_gecko_request(&format!("/exchanges/{}", id), None::<&[(&str, &str)]>)
+            .await
     }
 
     fn exchanges_volumechart() {
@@ -615,10 +1438,10 @@ impl GeckoClient {
     }
 
     /// Get BTC-to-Currency exchange rates
-    pub async fn exchangerates(&self) -> Result<ExchangeRates, reqwest::Error> {
+    pub async fn exchangerates(&self) -> Result<ExchangeRates, Error> {
         let url = "/exchange_rates";
 
-        self.send_gecko_request(url, None::<&[()]>).await
+        self.send_gecko_request(url, None::<&[(&str, &str)]>).await
     }
 
     fn search() {
@@ -630,9 +1453,9 @@ impl GeckoClient {
     }
 
     ///Get cryptocurrency global data
-    pub async fn global(&self) -> Result<GlobalData, reqwest::Error> {
+    pub async fn global(&self) -> Result<GlobalData, Error> {
         let url = "/global";
-        self.send_gecko_request(url, None::<&[()]>).await
+        self.send_gecko_request(url, None::<&[(&str, &str)]>).await
     }
 
     fn global_defi() {
@@ -643,3 +1466,820 @@ impl GeckoClient {
         todo!();
     }
 }
+
+fn plan_of(api_url: &str) -> Plan {
+    if api_url.contains("pro-api.coingecko.com") {
+        Plan::Pro
+    } else {
+        Plan::Public
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// The delay a response asks for in its Retry-After header, only the form in seconds is supported
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let seconds = response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// The reason of a 404 response, Coingecko sends it as `{"error": "coin not found"}`
+fn not_found_message(content: &str) -> String {
+    match serde_json::from_str::<Value>(content) {
+        Ok(Value::Object(body)) => match body.get("error") {
+            Some(Value::String(message)) => message.clone(),
+            _ => content.to_string(),
+        },
+        _ => content.to_string(),
+    }
+}
+
+fn cache_key(request: &reqwest::Request) -> String {
+    format!("{} {}", request.method(), request.url())
+}
+
+/// Splits the range between two unix timestamps into consecutive windows of at most `span`.
+/// Neighbouring windows share their boundary.
+fn time_windows(from: i64, to: i64, span: Duration) -> Vec<(i64, i64)> {
+    let span = (span.as_secs() as i64).max(1);
+    let mut windows = Vec::new();
+    let mut start = from;
+
+    while start < to {
+        let end = start.saturating_add(span).min(to);
+        windows.push((start, end));
+        start = end;
+    }
+
+    windows
+}
+
+/// Most requests a single market chart or OHLC range is split into
+const MAX_CHART_WINDOWS: usize = 100;
+
+/// Fails if a range takes more than [MAX_CHART_WINDOWS] requests with the interval
+fn check_window_count(endpoint: &str, windows: usize, interval: impl Debug) -> Result<(), Error> {
+    if windows <= MAX_CHART_WINDOWS {
+        return Ok(());
+    }
+
+    Err(Error::InvalidParams {
+        endpoint: endpoint.to_string(),
+        message: format!(
+            "the range needs {} requests with a granularity of {:?}, at most {} are sent",
+            windows, interval, MAX_CHART_WINDOWS
+        ),
+    })
+}
+
+/// Windows of a market chart range that each yield the granularity.
+/// Windows shorter than the granularity needs start earlier, the extra points are cut off when merging
+fn chart_windows(from: i64, to: i64, granularity: Granularity) -> Vec<(i64, i64)> {
+    let windows = match granularity.max_span() {
+        Some(span) => time_windows(from, to, span),
+        None if from < to => vec![(from, to)],
+        None => Vec::new(),
+    };
+    let min_span = granularity.min_span().as_secs() as i64;
+
+    windows
+        .into_iter()
+        .map(|(start, end)| (start.min(end - min_span), end))
+        .collect()
+}
+
+/// Merges the charts of several requests, points are ordered by time, the first point of a time wins
+/// and points outside of the range in unix milliseconds are dropped
+fn merge_market_charts(chunks: Vec<MarketChart>, from: i64, to: i64) -> MarketChart {
+    let mut merged = MarketChart::default();
+
+    for chunk in chunks {
+        merged.prices.extend(chunk.prices);
+        merged.market_caps.extend(chunk.market_caps);
+        merged.total_volumes.extend(chunk.total_volumes);
+    }
+
+    for column in [
+        &mut merged.prices,
+        &mut merged.market_caps,
+        &mut merged.total_volumes,
+    ] {
+        column.retain(|(time, _)| (from..=to).contains(time));
+        column.sort_by_key(|(time, _)| *time);
+        column.dedup_by_key(|(time, _)| *time);
+    }
+
+    merged
+}
+
+/// Stitches the candles of several requests in order of their time, the first candle of a time wins
+fn merge_candles(chunks: Vec<Vec<Candle>>) -> Vec<Candle> {
+    let mut candles = chunks.into_iter().flatten().collect::<Vec<_>>();
+    candles.sort_by_key(|candle| candle.time);
+    candles.dedup_by_key(|candle| candle.time);
+    candles
+}
+
+fn marketchart_params(
+    vs_currency: &str,
+    days: ChartDays,
+    interval: Option<Interval>,
+    precision: Option<Precision>,
+) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("vs_currency", vs_currency.to_string()),
+        ("days", days.to_string()),
+    ];
+
+    if let Some(interval) = interval {
+        params.push(("interval", interval.to_string()));
+    }
+    if let Some(precision) = precision {
+        params.push(("precision", precision.to_string()));
+    }
+
+    params
+}
+
+fn categories_params(order: Option<CategoryOrder>) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+
+    if let Some(order) = order {
+        params.push(("order", order.to_string()));
+    }
+
+    params
+}
+
+fn exchanges_params(per_page: Option<u16>, page: Option<u32>) -> [(&'static str, Option<u32>); 2] {
+    [("per_page", per_page.map(u32::from)), ("page", page)]
+}
+
+fn ohlc_params(
+    vs_currency: &str,
+    days: OhlcDays,
+    interval: Option<OhlcInterval>,
+) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("vs_currency", vs_currency.to_string()),
+        ("days", days.to_string()),
+    ];
+
+    if let Some(interval) = interval {
+        params.push(("interval", interval.to_string()));
+    }
+
+    params
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use futures_util::StreamExt;
+    use serde_json::Value;
+
+    use std::str::FromStr;
+
+    use crate::client::{
+        cache_key, categories_params, chart_windows, exchanges_params, marketchart_params,
+        merge_candles, merge_market_charts, not_found_message, ohlc_params, time_windows, Error,
+        GeckoClient, RateLimit, Retry, CACHE_CAPACITY,
+    };
+    use crate::drift::SchemaCheck;
+    use crate::endpoint::{CachePolicy, Endpoint, Get, Plan};
+    use crate::model::apimodels::{Candle, MarketChart};
+    use crate::model::common::{Amount, Ping};
+    use crate::model::identifiers::{AssetPlatformId, CoinId, ContractAddress, ExchangeId};
+    use crate::model::queryparams::{
+        CategoryOrder, ChartDays, CoinTickersRequest, CoinsMarketsRequest, ExchangeTickersRequest,
+        Granularity, Interval, Language, MarketOrder, OhlcDays, OhlcInterval, Paged, Precision,
+        PriceChange, TickerOrder,
+    };
+    use crate::model::timestamp::backend;
+
+    fn url(endpoint: &impl Endpoint) -> String {
+        GeckoClient::default()
+            .build_request(endpoint)
+            .unwrap()
+            .url()
+            .to_string()
+    }
+
+    fn markets_url(request: &CoinsMarketsRequest) -> String {
+        url(request)
+    }
+
+    #[test]
+    fn coins_markets_minimal_url() {
+        assert_eq!(
+            markets_url(&CoinsMarketsRequest::new("usd")),
+            "https://api.coingecko.com/api/v3/coins/markets?vs_currency=usd&sparkline=false"
+        );
+    }
+
+    #[test]
+    fn coins_markets_full_url() {
+        let request = CoinsMarketsRequest::new("eur")
+            .ids(["bitcoin", "ethereum"])
+            .category("layer-1")
+            .order(MarketOrder::VolumeDesc)
+            .per_page(250)
+            .page(3)
+            .sparkline(true)
+            .price_change_percentage(&[PriceChange::Hours1, PriceChange::Days200])
+            .locale(Language::ZhTw)
+            .precision(Precision::Full);
+
+        assert_eq!(
+            markets_url(&request),
+            "https://api.coingecko.com/api/v3/coins/markets?vs_currency=eur\
+             &ids=bitcoin%2Cethereum&category=layer-1&order=volume_desc&per_page=250&page=3\
+             &sparkline=true&price_change_percentage=1h%2C200d&locale=zh-tw&precision=full"
+        );
+    }
+
+    #[test]
+    fn coins_markets_precision_decimals_url() {
+        let request = CoinsMarketsRequest::new("usd").precision(Precision::Decimals(8));
+
+        assert_eq!(
+            markets_url(&request),
+            "https://api.coingecko.com/api/v3/coins/markets?vs_currency=usd&sparkline=false&precision=8"
+        );
+    }
+
+    #[test]
+    fn coins_ohlc_url() {
+        let request = Get::<Value>::new("/coins/bitcoin/ohlc")
+            .params(&ohlc_params(
+                "usd",
+                OhlcDays::Max,
+                Some(OhlcInterval::Daily),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/bitcoin/ohlc?vs_currency=usd&days=max&interval=daily"
+        );
+    }
+
+    #[test]
+    fn ohlc_range_is_split_into_windows() {
+        let day = 24 * 60 * 60;
+        let windows = time_windows(0, 400 * day, OhlcInterval::Daily.max_span());
+
+        assert_eq!(
+            windows,
+            vec![
+                (0, 180 * day),
+                (180 * day, 360 * day),
+                (360 * day, 400 * day)
+            ]
+        );
+        assert_eq!(
+            time_windows(day, day, OhlcInterval::Hourly.max_span()),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn ohlc_chunks_are_merged_in_order() {
+        let candle = |seconds: i64, close: &str| Candle {
+            time: backend::from_unix_seconds(seconds).unwrap(),
+            open: Amount::from(1u8),
+            high: Amount::from(1u8),
+            low: Amount::from(1u8),
+            close: Amount::from_str(close).unwrap(),
+        };
+
+        let merged = merge_candles(vec![
+            vec![candle(7200, "2"), candle(3600, "1")],
+            vec![candle(7200, "3"), candle(10800, "4")],
+        ]);
+
+        assert_eq!(
+            merged,
+            vec![candle(3600, "1"), candle(7200, "2"), candle(10800, "4")]
+        );
+    }
+
+    #[test]
+    fn chart_windows_yield_the_granularity() {
+        let day = 24 * 60 * 60;
+
+        assert_eq!(
+            chart_windows(0, 2 * day, Granularity::FiveMinutes),
+            vec![(0, day), (day, 2 * day)]
+        );
+        assert_eq!(
+            chart_windows(100 * day, 101 * day, Granularity::Hourly),
+            vec![(99 * day, 101 * day)]
+        );
+        assert_eq!(
+            chart_windows(0, 120 * day, Granularity::Hourly),
+            vec![(0, 90 * day), (90 * day, 120 * day)]
+        );
+        assert_eq!(
+            chart_windows(200 * day, 230 * day, Granularity::Daily),
+            vec![(139 * day, 230 * day)]
+        );
+    }
+
+    #[tokio::test]
+    async fn long_five_minute_ranges_are_rejected() {
+        let client = GeckoClient::new("http://127.0.0.1:9");
+        let from = backend::from_unix_seconds(0).unwrap();
+        let to = backend::from_unix_seconds(365 * 24 * 60 * 60).unwrap();
+
+        let error = client
+            .coins_marketchart_range("bitcoin", "usd", from, to, Granularity::FiveMinutes)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParams { .. }));
+        assert!(error.to_string().contains("needs 365 requests"));
+    }
+
+    #[tokio::test]
+    async fn long_hourly_ohlc_ranges_are_rejected() {
+        let client = GeckoClient::new("http://127.0.0.1:9").with_plan(Plan::Pro);
+        let from = backend::from_unix_seconds(0).unwrap();
+        let to = backend::from_unix_seconds(10 * 365 * 24 * 60 * 60).unwrap();
+
+        let error = client
+            .coins_ohlc_range("bitcoin", "usd", from, to, OhlcInterval::Hourly)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParams { .. }));
+        assert!(error.to_string().contains("needs 118 requests"));
+    }
+
+    #[test]
+    fn market_chart_chunks_are_merged() {
+        let point = |time: i64, price: u8| (time, Some(Amount::from(price)));
+        let chunk = |points: Vec<(i64, Option<Amount>)>| {
+            let mut chart = MarketChart::default();
+            chart.prices.extend(points.clone());
+            chart.market_caps.extend(points.clone());
+            chart.total_volumes.extend(points);
+            chart
+        };
+
+        let merged = merge_market_charts(
+            vec![
+                chunk(vec![point(1000, 1), point(2000, 2), point(3000, 3)]),
+                chunk(vec![point(3000, 4), point(4000, 5), point(9000, 6)]),
+            ],
+            2000,
+            5000,
+        );
+
+        let expected = vec![point(2000, 2), point(3000, 3), point(4000, 5)];
+        assert_eq!(merged.prices, expected);
+        assert_eq!(merged.market_caps, expected);
+        assert_eq!(merged.total_volumes, expected);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_spaces_requests() {
+        let rate_limit = RateLimit::new(Duration::from_millis(20));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            rate_limit.wait().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn ranges_fall_back_to_the_limit_of_the_plan() {
+        let interval = |client: GeckoClient| client.series_limit().map(|limit| limit.interval);
+
+        assert_eq!(
+            interval(GeckoClient::default()),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            interval(GeckoClient::default().with_plan(Plan::Pro)),
+            Some(Duration::from_millis(120))
+        );
+        assert_eq!(interval(GeckoClient::default().with_rate_limit(60)), None);
+    }
+
+    #[test]
+    fn coins_tickers_url() {
+        assert_eq!(
+            url(&CoinTickersRequest::new("bitcoin")),
+            "https://api.coingecko.com/api/v3/coins/bitcoin/tickers?include_exchange_logo=false&depth=false"
+        );
+
+        let request = CoinTickersRequest::new(CoinId::new("ethereum"))
+            .exchange_ids([ExchangeId::new("binance"), ExchangeId::new("gdax")])
+            .include_exchange_logo(true)
+            .page(2)
+            .order(TickerOrder::VolumeAsc)
+            .depth(true);
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/ethereum/tickers?exchange_ids=binance%2Cgdax\
+             &include_exchange_logo=true&page=2&order=volume_asc&depth=true"
+        );
+    }
+
+    #[tokio::test]
+    async fn ticker_pages_end_after_an_error() {
+        let client = GeckoClient::new("http://127.0.0.1:9");
+        let mut pages = Box::pin(client.coins_tickers_pages(&CoinTickersRequest::new("bitcoin")));
+
+        assert!(pages.next().await.unwrap().is_err());
+        assert!(pages.next().await.is_none());
+
+        let mut pages =
+            Box::pin(client.exchanges_tickers_pages(&ExchangeTickersRequest::new("kraken")));
+        assert!(pages.next().await.unwrap().is_err());
+        assert!(pages.next().await.is_none());
+    }
+
+    #[test]
+    fn exchanges_tickers_url() {
+        let request = ExchangeTickersRequest::new(ExchangeId::new("binance"))
+            .coin_ids([CoinId::new("bitcoin"), CoinId::new("ethereum")])
+            .page(3)
+            .order(TickerOrder::TrustScoreDesc)
+            .depth(true);
+
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/exchanges/binance/tickers?coin_ids=bitcoin%2Cethereum\
+             &include_exchange_logo=false&page=3&order=trust_score_desc&depth=true"
+        );
+        assert_eq!(
+            url(&request.next_page()),
+            "https://api.coingecko.com/api/v3/exchanges/binance/tickers?coin_ids=bitcoin%2Cethereum\
+             &include_exchange_logo=false&page=4&order=trust_score_desc&depth=true"
+        );
+        assert_eq!(
+            url(&ExchangeTickersRequest::new("kraken").next_page()),
+            "https://api.coingecko.com/api/v3/exchanges/kraken/tickers?include_exchange_logo=false&page=2&depth=false"
+        );
+    }
+
+    #[test]
+    fn categories_url() {
+        let request = Get::<Value>::new("/coins/categories")
+            .params(&categories_params(Some(
+                CategoryOrder::MarketCapChange24hDesc,
+            )))
+            .unwrap();
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/categories?order=market_cap_change_24h_desc"
+        );
+
+        let request = Get::<Value>::new("/coins/categories")
+            .params(&categories_params(None))
+            .unwrap();
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/categories"
+        );
+    }
+
+    #[test]
+    fn all_exchanges_url() {
+        let request = Get::<Value>::new("/exchanges")
+            .params(&exchanges_params(Some(250), Some(3)))
+            .unwrap();
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/exchanges?per_page=250&page=3"
+        );
+
+        let request = Get::<Value>::new("/exchanges")
+            .params(&exchanges_params(None, Some(2)))
+            .unwrap();
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/exchanges?page=2"
+        );
+    }
+
+    #[test]
+    fn coins_marketchart_url() {
+        let request = Get::<Value>::new("/coins/bitcoin/market_chart")
+            .params(&marketchart_params(
+                "usd",
+                ChartDays::Days(14),
+                Some(Interval::Hourly),
+                Some(Precision::Decimals(2)),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/bitcoin/market_chart?vs_currency=usd&days=14&interval=hourly&precision=2"
+        );
+
+        let request = Get::<Value>::new("/coins/bitcoin/market_chart")
+            .params(&marketchart_params("eur", ChartDays::Max, None, None))
+            .unwrap();
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/bitcoin/market_chart?vs_currency=eur&days=max"
+        );
+    }
+
+    #[test]
+    fn raw_endpoint_url() {
+        let request = Get::<Value>::new("coins/bitcoin/tickers")
+            .params(&[("page", "2"), ("depth", "true")])
+            .unwrap();
+
+        assert_eq!(
+            url(&request),
+            "https://api.coingecko.com/api/v3/coins/bitcoin/tickers?page=2&depth=true"
+        );
+        assert_eq!(
+            url(&Get::<Value>::new("/ping")),
+            "https://api.coingecko.com/api/v3/ping"
+        );
+    }
+
+    #[tokio::test]
+    async fn pro_endpoints_need_the_pro_plan() {
+        let client = GeckoClient::default();
+        let result = client
+            .coins_ohlc("bitcoin", "usd", OhlcDays::Max, Some(OhlcInterval::Daily))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::PlanRequired {
+                plan: Plan::Pro,
+                ..
+            })
+        ));
+        assert_eq!(
+            GeckoClient::new("https://pro-api.coingecko.com/api/v3").plan(),
+            Plan::Pro
+        );
+    }
+
+    #[tokio::test]
+    async fn contract_marketchart_checks_the_interval_plan() {
+        let result = GeckoClient::default()
+            .contract_marketchart(
+                &AssetPlatformId::new("ethereum"),
+                &ContractAddress::new("0x1F9840a85d5aF5bf1D1762F925BDADdC4201F984"),
+                "usd",
+                ChartDays::Days(7),
+                Some(Interval::Hourly),
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::PlanRequired { endpoint, plan: Plan::Pro })
+                if endpoint == "/coins/ethereum/contract/0x1f9840a85d5af5bf1d1762f925bdaddc4201f984/market_chart"
+        ));
+    }
+
+    struct CachedPing;
+
+    impl Endpoint for CachedPing {
+        type Response = Ping;
+
+        fn path(&self) -> String {
+            "/ping".to_string()
+        }
+
+        fn cache_policy(&self) -> CachePolicy {
+            CachePolicy::Ttl(Duration::from_secs(60))
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_responses_are_reused() {
+        // nothing listens on the discard port, every request that is not cached fails
+        let client = GeckoClient::new("http://127.0.0.1:9");
+        let request = client.build_request(&CachedPing).unwrap();
+        client.store(
+            cache_key(&request),
+            r#"{"gecko_says": "(V3) To the Moon!"}"#.to_string(),
+            Duration::from_secs(60),
+        );
+
+        let ping = client.execute(&CachedPing).await.unwrap();
+        assert_eq!(ping.gecko_says, "(V3) To the Moon!");
+
+        client.clear_cache();
+        assert!(client.execute(&CachedPing).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn raw_requests_use_the_cache_of_the_client() {
+        // the server answers once, the second request can only be served from the cache
+        let url = serve(vec![PONG]).await;
+        let client = GeckoClient::new(url).with_cache_ttl(Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let ping = client.get_json("/ping", &[]).await.unwrap();
+            assert_eq!(ping["gecko_says"], "(V3) To the Moon!");
+        }
+
+        client.clear_cache();
+        assert!(client.get_json("/ping", &[]).await.is_err());
+    }
+
+    #[test]
+    fn cache_drops_expired_and_oldest_responses() {
+        let client = GeckoClient::default();
+        client.store("expired".to_string(), String::new(), Duration::ZERO);
+        for i in 0..CACHE_CAPACITY {
+            client.store(i.to_string(), i.to_string(), Duration::from_secs(60));
+        }
+        assert!(!client.cache.lock().unwrap().contains_key("expired"));
+
+        client.store(
+            "new".to_string(),
+            "new".to_string(),
+            Duration::from_secs(60),
+        );
+        // the oldest response makes room for the new one
+        assert_eq!(client.cache.lock().unwrap().len(), CACHE_CAPACITY);
+        assert_eq!(client.cached("0"), None);
+        assert_eq!(client.cached("1").as_deref(), Some("1"));
+        assert_eq!(client.cached("new").as_deref(), Some("new"));
+    }
+
+    #[cfg(all(feature = "decimal", not(feature = "extra-fields")))]
+    #[test]
+    fn checked_responses_keep_long_decimals() {
+        use crate::client::Prices;
+
+        let content = r#"{"bitcoin": {"usd": 67187.3358936500001234}}"#;
+
+        for schema_check in [SchemaCheck::Off, SchemaCheck::Report] {
+            let client = GeckoClient::default().with_schema_check(schema_check);
+            let prices: Prices<String> = client
+                .deserialize_response("/simple/price", content)
+                .unwrap();
+            assert_eq!(
+                prices.0["bitcoin"]["usd"],
+                Amount::from_str("67187.3358936500001234").unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn precision_above_18_decimals_is_rejected() {
+        let client = GeckoClient::new("http://127.0.0.1:9");
+
+        let request = CoinsMarketsRequest::new("usd").precision(Precision::Decimals(19));
+        let error = client.coins_markets(&request).await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid query parameters for /coins/markets: precision: 19 decimal places, at most 18 are supported"
+        );
+
+        let error = client
+            .coins_marketchart(
+                "bitcoin",
+                "usd",
+                ChartDays::Days(1),
+                None,
+                Some(Precision::Decimals(200)),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidParams { .. }));
+
+        // 18 places pass the check and fail on the connection
+        let request = CoinsMarketsRequest::new("usd").precision(Precision::Decimals(18));
+        assert!(matches!(
+            client.coins_markets(&request).await,
+            Err(Error::Http(_))
+        ));
+    }
+
+    #[test]
+    fn not_found_errors_carry_the_reason() {
+        assert_eq!(
+            not_found_message(r#"{"error": "coin not found"}"#),
+            "coin not found"
+        );
+        assert_eq!(not_found_message("Not Found"), "Not Found");
+
+        let error = Error::NotFound {
+            endpoint: "/coins/ethereum/contract/0x0".to_string(),
+            message: "coin not found".to_string(),
+        };
+        assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND));
+        assert_eq!(
+            error.to_string(),
+            "/coins/ethereum/contract/0x0 was not found: coin not found"
+        );
+    }
+
+    /// Serves the responses in order, one per connection, and returns the url of the server
+    async fn serve(responses: Vec<&'static str>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        url
+    }
+
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const RATE_LIMITED: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const PONG: &str =
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 34\r\n\
+         Connection: close\r\n\r\n{\"gecko_says\":\"(V3) To the Moon!\"}";
+
+    #[tokio::test]
+    async fn retries_rate_limited_and_unavailable_responses() {
+        let url = serve(vec![UNAVAILABLE, RATE_LIMITED, PONG]).await;
+        let client = GeckoClient::new(url).with_retry(2, Duration::from_millis(1));
+        assert_eq!(client.ping().await.unwrap().gecko_says, "(V3) To the Moon!");
+
+        let url = serve(vec![UNAVAILABLE, RATE_LIMITED]).await;
+        let client = GeckoClient::new(url).with_retry(1, Duration::from_millis(1));
+        let error = client.ping().await.unwrap_err();
+        assert_eq!(error.status(), Some(reqwest::StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_a_minute() {
+        let retry = Retry {
+            max_retries: 10,
+            base_delay: Duration::from_secs(1),
+        };
+
+        assert_eq!(retry.backoff(0), Duration::from_secs(1));
+        assert_eq!(retry.backoff(3), Duration::from_secs(8));
+        assert_eq!(retry.backoff(9), Duration::from_secs(60));
+    }
+
+    const PING_WITH_NEW_FIELD: &str = r#"{"gecko_says": "(V3) To the Moon!", "status": "ok"}"#;
+
+    #[test]
+    fn deserialize_error_does_not_panic() {
+        let result =
+            GeckoClient::default().deserialize_response::<Ping>("/ping", r#"{"gecko_says": 1}"#);
+
+        assert!(matches!(result, Err(Error::Deserialize { .. })));
+    }
+
+    #[test]
+    fn report_mode_collects_drift() {
+        let client = GeckoClient::default().with_schema_check(SchemaCheck::Report);
+        let ping = client.deserialize_response::<Ping>("/ping", PING_WITH_NEW_FIELD);
+
+        assert_eq!(ping.unwrap().gecko_says, "(V3) To the Moon!");
+        let reports = client.drift_reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].endpoint, "/ping");
+        assert!(reports[0].new_fields.contains("status"));
+    }
+
+    #[test]
+    fn strict_mode_fails_on_drift() {
+        let client = GeckoClient::default().with_schema_check(SchemaCheck::Strict);
+        let result = client.deserialize_response::<Ping>("/ping", PING_WITH_NEW_FIELD);
+
+        assert!(
+            matches!(result, Err(Error::SchemaDrift(report)) if report.new_fields.contains("status"))
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Detection of differences between the responses of Coingecko and the models of this crate.
+//!
+//! Coingecko adds and changes fields without notice. A [DriftReport] lists the fields of a response
+//! that are new, missing or have a different type than the model expects. The [crate::client::GeckoClient]
+//! collects these reports per endpoint when it runs with [SchemaCheck::Report] or [SchemaCheck::Strict].
+
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// How the client compares responses to the models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchemaCheck {
+    /// Responses are deserialized without comparing them to the model
+    #[default]
+    Off,
+    /// Drift is logged and collected, see [crate::client::GeckoClient::drift_reports]
+    Report,
+    /// Drift is collected and the request fails with [crate::error::Error::SchemaDrift]
+    Strict,
+}
+
+/// A field whose JSON type in the response differs from the model
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct RetypedField {
+    pub path: String,
+    pub expected: String,
+    pub found: String,
+}
+
+/// Differences between a response and the model of its endpoint
+///
+/// Field paths are dot separated, array elements are written as `[]`, eg. `tickers[].market.name`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct DriftReport {
+    pub endpoint: String,
+    /// Fields present in the response but unknown to the model
+    pub new_fields: BTreeSet<String>,
+    /// Fields of the model that are not present in the response
+    pub missing_fields: BTreeSet<String>,
+    /// Fields whose type in the response differs from the model
+    pub retyped_fields: BTreeSet<RetypedField>,
+}
+
+impl DriftReport {
+    /// Compares a raw response of the endpoint to the model `T`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::drift::DriftReport;
+    /// use rustgecko::model::common::Ping;
+    /// let response = serde_json::json!({"gecko_says": "(V3) To the Moon!", "status": "ok"});
+    /// let report = DriftReport::detect::<Ping>("/ping", &response);
+    /// assert!(report.new_fields.contains("status"));
+    /// ```
+    pub fn detect<T: DeserializeOwned + Serialize>(
+        endpoint: &str,
+        response: &Value,
+    ) -> DriftReport {
+        check::<T>(endpoint, response).1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.new_fields.is_empty()
+            && self.missing_fields.is_empty()
+            && self.retyped_fields.is_empty()
+    }
+
+    /// Adds the findings of another report of the same endpoint
+    pub fn merge(&mut self, other: DriftReport) {
+        self.new_fields.extend(other.new_fields);
+        self.missing_fields.extend(other.missing_fields);
+        self.retyped_fields.extend(other.retyped_fields);
+    }
+}
+
+impl Display for DriftReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Schema drift in {}:", self.endpoint)?;
+        for field in &self.new_fields {
+            write!(f, " new `{}`;", field)?;
+        }
+        for field in &self.missing_fields {
+            write!(f, " missing `{}`;", field)?;
+        }
+        for field in &self.retyped_fields {
+            write!(
+                f,
+                " `{}` expected {} found {};",
+                field.path, field.expected, field.found
+            )?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    static SKIP_EXTRA_FIELDS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// True while a model is serialized for a comparison, [crate::model::common::Extra] is left out then
+/// so collected unknown fields still count as new.
+pub(crate) fn skip_extra_fields() -> bool {
+    SKIP_EXTRA_FIELDS.with(Cell::get)
+}
+
+/// Deserializes the response into `T` and compares the result to the response
+pub(crate) fn check<T: DeserializeOwned + Serialize>(
+    endpoint: &str,
+    response: &Value,
+) -> (
+    Result<T, serde_path_to_error::Error<serde_json::Error>>,
+    DriftReport,
+) {
+    let mut report = DriftReport {
+        endpoint: endpoint.to_string(),
+        ..DriftReport::default()
+    };
+
+    let result: Result<T, _> = serde_path_to_error::deserialize(response);
+    match &result {
+        Ok(model) => {
+            SKIP_EXTRA_FIELDS.with(|skip| skip.set(true));
+            let model_value = serde_json::to_value(model);
+            SKIP_EXTRA_FIELDS.with(|skip| skip.set(false));
+
+            if let Ok(model_value) = model_value {
+                compare(response, &model_value, String::new(), &mut report);
+            }
+        }
+        Err(err) => record_error(err, &mut report),
+    }
+
+    (result, report)
+}
+
+fn record_error(err: &serde_path_to_error::Error<serde_json::Error>, report: &mut DriftReport) {
+    let path = match err.path().to_string() {
+        root if root == "." => String::new(),
+        path => path,
+    };
+    let message = err.inner().to_string();
+
+    if let Some(field) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        report.missing_fields.insert(child(&path, field));
+    } else if let Some((found, expected)) = message
+        .strip_prefix("invalid type: ")
+        .and_then(|rest| rest.split_once(", expected "))
+    {
+        report.retyped_fields.insert(RetypedField {
+            path,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        });
+    } else {
+        report.retyped_fields.insert(RetypedField {
+            path,
+            expected: "a valid value".to_string(),
+            found: message,
+        });
+    }
+}
+
+fn compare(response: &Value, model: &Value, path: String, report: &mut DriftReport) {
+    match (response, model) {
+        (Value::Object(response), Value::Object(model)) => {
+            for (key, value) in response {
+                match model.get(key) {
+                    Some(model_value) => compare(value, model_value, child(&path, key), report),
+                    None => {
+                        report.new_fields.insert(child(&path, key));
+                    }
+                }
+            }
+            for (key, value) in model {
+                if !value.is_null() && !response.contains_key(key) {
+                    report.missing_fields.insert(child(&path, key));
+                }
+            }
+        }
+        (Value::Array(response), Value::Array(model)) => {
+            for (value, model_value) in response.iter().zip(model) {
+                compare(value, model_value, format!("{}[]", path), report);
+            }
+        }
+        (Value::Null, _) | (_, Value::Null) => {}
+        (response, model) if kind(response) != kind(model) => {
+            report.retyped_fields.insert(RetypedField {
+                path,
+                expected: kind(model).to_string(),
+                found: kind(response).to_string(),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::drift::{check, DriftReport, RetypedField};
+    use crate::model::apimodels::AssetPlatform;
+
+    #[test]
+    fn matching_response_has_no_drift() {
+        let response = json!([{
+            "id": "ethereum",
+            "chain_identifier": 1,
+            "name": "Ethereum",
+            "shortname": "eth"
+        }]);
+
+        assert!(
+            DriftReport::detect::<Vec<AssetPlatform>>("/asset_platforms", &response).is_empty()
+        );
+    }
+
+    #[test]
+    fn new_fields_are_reported_with_their_path() {
+        let response = json!([{
+            "id": "ethereum",
+            "chain_identifier": 1,
+            "name": "Ethereum",
+            "shortname": "eth",
+            "native_coin_id": "ethereum"
+        }]);
+
+        let report = DriftReport::detect::<Vec<AssetPlatform>>("/asset_platforms", &response);
+
+        assert_eq!(
+            report.new_fields.into_iter().collect::<Vec<_>>(),
+            vec!["[].native_coin_id"]
+        );
+    }
+
+    #[test]
+    fn missing_and_retyped_fields_are_reported() {
+        let missing = json!([{"id": "ethereum", "chain_identifier": 1, "name": "Ethereum"}]);
+        let (result, report) = check::<Vec<AssetPlatform>>("/asset_platforms", &missing);
+
+        assert!(result.is_err());
+        assert!(report.missing_fields.contains("[0].shortname"));
+
+        let retyped = json!([{
+            "id": "ethereum",
+            "chain_identifier": "1",
+            "name": "Ethereum",
+            "shortname": "eth"
+        }]);
+        let report = DriftReport::detect::<Vec<AssetPlatform>>("/asset_platforms", &retyped);
+
+        assert_eq!(
+            report.retyped_fields.into_iter().collect::<Vec<_>>(),
+            vec![RetypedField {
+                path: "[0].chain_identifier".to_string(),
+                expected: "i64".to_string(),
+                found: "string \"1\"".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Typed description of an API endpoint.
+//!
+//! Every request of the [crate::client::GeckoClient] is an [Endpoint] run by [crate::client::GeckoClient::execute],
+//! the built-in methods as well as endpoints defined in other crates.
+//! They all share the same client, plan check, cache and error handling.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::Error;
+
+/// Coingecko plan an endpoint or parameter is available on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Plan {
+    /// The public API, with or without a demo key
+    #[default]
+    Public,
+    /// The paid plans served from pro-api.coingecko.com
+    Pro,
+}
+
+impl Plan {
+    /// Requests per minute the plan allows, for [Plan::Pro] the lowest of the paid plans
+    pub fn calls_per_minute(&self) -> u32 {
+        match self {
+            Plan::Public => 30,
+            Plan::Pro => 500,
+        }
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Plan::Public => write!(f, "public"),
+            Plan::Pro => write!(f, "pro"),
+        }
+    }
+}
+
+/// Whether responses of an endpoint may be served from the cache of the client
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Every call is sent to the API
+    #[default]
+    NoCache,
+    /// Responses are reused for identical requests until they are older than the duration.
+    /// The client keeps the 256 most recent responses, expired ones are dropped when a new one is stored
+    Ttl(Duration),
+}
+
+/// An endpoint of the API and the model of its response
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use serde::{Deserialize, Serialize};
+/// use rustgecko::client::GeckoClient;
+/// use rustgecko::endpoint::{CachePolicy, Endpoint};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Trending {
+///     coins: Vec<serde_json::Value>,
+/// }
+///
+/// struct SearchTrending;
+///
+/// impl Endpoint for SearchTrending {
+///     type Response = Trending;
+///
+///     fn path(&self) -> String {
+///         "/search/trending".to_string()
+///     }
+///
+///     fn cache_policy(&self) -> CachePolicy {
+///         CachePolicy::Ttl(Duration::from_secs(10 * 60))
+///     }
+/// }
+///
+/// async fn trending() {
+///     let trending = GeckoClient::default().execute(&SearchTrending).await.unwrap();
+/// }
+/// ```
+pub trait Endpoint {
+    /// The model of the response. It has to implement Serialize so it can be checked for drift
+    type Response: DeserializeOwned + Serialize;
+
+    /// The path relative to the api url, eg. /coins/markets
+    fn path(&self) -> String;
+
+    /// The query parameters in the order they are sent to the API
+    fn query(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    /// The plan the client needs to call the endpoint with these parameters
+    fn plan(&self) -> Plan {
+        Plan::Public
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::NoCache
+    }
+}
+
+/// GET request to any path, the endpoint behind the built-in methods of [crate::client::GeckoClient]
+pub(crate) struct Get<D> {
+    path: String,
+    query: Vec<(String, String)>,
+    plan: Plan,
+    cache_policy: CachePolicy,
+    response: PhantomData<fn() -> D>,
+}
+
+impl<D> Get<D> {
+    pub(crate) fn new(path: impl Into<String>) -> Self {
+        Get {
+            path: format!("/{}", path.into().trim_start_matches('/')),
+            query: Vec::new(),
+            plan: Plan::Public,
+            cache_policy: CachePolicy::NoCache,
+            response: PhantomData,
+        }
+    }
+
+    /// Adds query parameters as pairs of keys and values, in the order they are given.
+    /// Values are sent in their JSON form without quotes, None values are left out.
+    /// Values that are not a string, number, bool or None have no query form and are an error.
+    pub(crate) fn params<K: AsRef<str>, V: Serialize>(
+        mut self,
+        params: &[(K, V)],
+    ) -> Result<Self, Error> {
+        for (key, value) in params {
+            let invalid = |message: String| Error::InvalidParams {
+                endpoint: self.path.clone(),
+                message: format!("{}: {}", key.as_ref(), message),
+            };
+            let value = match serde_json::to_value(value).map_err(|err| invalid(err.to_string()))? {
+                Value::Null => continue,
+                Value::String(value) => value,
+                value @ (Value::Bool(_) | Value::Number(_)) => value.to_string(),
+                value => return Err(invalid(format!("{} is not a query value", value))),
+            };
+            self.query.push((key.as_ref().to_string(), value));
+        }
+
+        Ok(self)
+    }
+
+    pub(crate) fn requires(mut self, plan: Plan) -> Self {
+        self.plan = plan;
+        self
+    }
+
+    pub(crate) fn cache(mut self, cache_policy: CachePolicy) -> Self {
+        self.cache_policy = cache_policy;
+        self
+    }
+}
+
+impl<D: DeserializeOwned + Serialize> Endpoint for Get<D> {
+    type Response = D;
+
+    fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        self.query.clone()
+    }
+
+    fn plan(&self) -> Plan {
+        self.plan
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        self.cache_policy
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+
+    use crate::endpoint::{Endpoint, Get};
+
+    #[test]
+    fn get_query_from_pairs() {
+        let endpoint = Get::<Value>::new("coins/bitcoin")
+            .params(&[("localization", false), ("tickers", true)])
+            .unwrap()
+            .params(&[("page", Some(2)), ("per_page", None)])
+            .unwrap()
+            .params(&[("ids", "bitcoin,ethereum".to_string())])
+            .unwrap();
+
+        assert_eq!(endpoint.path(), "/coins/bitcoin");
+        assert_eq!(
+            endpoint.query(),
+            vec![
+                ("localization".to_string(), "false".to_string()),
+                ("tickers".to_string(), "true".to_string()),
+                ("page".to_string(), "2".to_string()),
+                ("ids".to_string(), "bitcoin,ethereum".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_params_are_an_error() {
+        let error = Get::<Value>::new("coins/markets")
+            .params(&[("ids", vec!["bitcoin", "ethereum"])])
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            r#"Invalid query parameters for /coins/markets: ids: ["bitcoin","ethereum"] is not a query value"#
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use reqwest::StatusCode;
+
+use crate::drift::DriftReport;
+use crate::endpoint::Plan;
+
+/// Errors returned by the [crate::client::GeckoClient]
+#[derive(Debug)]
+pub enum Error {
+    /// The request failed or Coingecko answered with a 4XX/5XX status code
+    Http(reqwest::Error),
+    /// The response could not be deserialized into the model of the endpoint
+    Deserialize {
+        endpoint: String,
+        source: serde_path_to_error::Error<serde_json::Error>,
+    },
+    /// The response does not match the model of the endpoint, only returned in [crate::drift::SchemaCheck::Strict] mode
+    SchemaDrift(DriftReport),
+    /// The endpoint or one of its parameters is not available on the plan of the client, see [crate::client::GeckoClient::with_plan]
+    PlanRequired { endpoint: String, plan: Plan },
+    /// Coingecko does not know the requested id or contract address, the message is the reason given by the API
+    NotFound { endpoint: String, message: String },
+    /// A parameter is malformed, eg. a list or a map where the query string needs a single value,
+    /// or a range that would take too many requests
+    InvalidParams { endpoint: String, message: String },
+}
+
+impl Error {
+    /// The status code of the response if the error was caused by a 4XX/5XX response
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Http(err) => err.status(),
+            Error::NotFound { .. } => Some(StatusCode::NOT_FOUND),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(err) => write!(f, "Request to Coingecko failed: {}", err),
+            Error::Deserialize { endpoint, source } => write!(
+                f,
+                "Failed to deserialize response from {} at {}: {}",
+                endpoint,
+                source.path(),
+                source.inner()
+            ),
+            Error::SchemaDrift(report) => write!(f, "{}", report),
+            Error::PlanRequired { endpoint, plan } => {
+                write!(f, "{} requires the {} plan", endpoint, plan)
+            }
+            Error::NotFound { endpoint, message } => {
+                write!(f, "{} was not found: {}", endpoint, message)
+            }
+            Error::InvalidParams { endpoint, message } => {
+                write!(f, "Invalid query parameters for {}: {}", endpoint, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(err) => Some(err),
+            Error::Deserialize { source, .. } => Some(source),
+            Error::SchemaDrift(_)
+            | Error::PlanRequired { .. }
+            | Error::NotFound { .. }
+            | Error::InvalidParams { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
@@ -4,6 +4,9 @@
 extern crate core;
 
 pub mod client;
+pub mod drift;
+pub mod endpoint;
+pub mod error;
 pub mod model;
 
 #[cfg(test)]
@@ -16,7 +19,10 @@ mod test {
     use time::macros::date;
 
     use crate::client::GeckoClient;
-    use crate::model::queryparams::{MarketOrder, PriceChange};
+    use crate::model::identifiers::AssetPlatformId;
+    use crate::model::queryparams::{
+        ChartDays, CoinsMarketsRequest, Localize, MarketOrder, PriceChange,
+    };
 
     fn init() {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -49,17 +55,12 @@ mod test {
         let mut response;
         let mut page = 0;
         loop {
-            response = client
-                .coins_markets(
-                    "usd",
-                    None,
-                    MarketOrder::MarketCapDesc,
-                    Some(&price_changes),
-                    true,
-                    Some(page),
-                )
-                .await
-                .unwrap();
+            let request = CoinsMarketsRequest::new("usd")
+                .order(MarketOrder::MarketCapDesc)
+                .price_change_percentage(&price_changes)
+                .sparkline(true)
+                .page(page);
+            response = client.coins_markets(&request).await.unwrap();
 
             if response.is_empty() {
                 break;
@@ -76,8 +77,11 @@ mod test {
 
         for i in 1..list.len() {
             thread::sleep(Duration::from_secs(5));
-            let id = &list.get(i).unwrap().id;
-            if let Err(error) = client.coins(id, true, true, true, true, true, true).await {
+            let id = list.get(i).unwrap().id.as_str();
+            if let Err(error) = client
+                .coins(id, Localize::All, true, true, true, true, true)
+                .await
+            {
                 println!("{}", error);
                 break;
             }
@@ -91,7 +95,7 @@ mod test {
         let client = GeckoClient::default();
 
         let res = client
-            .coins_history("bitcoin", date!(2022 - 10 - 1), None)
+            .coins_history("bitcoin", date!(2022 - 10 - 1), Localize::None)
             .await
             .unwrap();
         info!("{:#?}", res);
@@ -103,7 +107,7 @@ mod test {
         init();
         let client = GeckoClient::default();
         let res = client
-            .coins_marketchart("bitcoin", "usd", "max", None)
+            .coins_marketchart("bitcoin", "usd", ChartDays::Max, None, None)
             .await
             .unwrap();
         info!("{:#?}", res);
@@ -150,11 +154,11 @@ mod test {
         let client = GeckoClient::default();
         let res = client
             .simple_token_price(
-                "ethereum",
+                &AssetPlatformId::new("ethereum"),
                 &["usd", "eur"],
                 &[
-                    "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984",
-                    "0xf629cbd94d3791c9250152bd8dfbdf380e2a3b9c",
+                    "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984".into(),
+                    "0xf629cbd94d3791c9250152bd8dfbdf380e2a3b9c".into(),
                 ],
                 true,
                 true,
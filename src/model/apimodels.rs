@@ -1,35 +1,58 @@
+use crate::model::coins::translation;
 use crate::model::common::*;
+use crate::model::identifiers::{AssetPlatformId, CoinId, ExchangeId};
+use crate::model::queryparams::{Granularity, Language, Localize};
+use crate::model::timestamp::{DateTime, Rfc3339, UnixMillis};
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use std::collections::HashMap;
 
 //TODO: test this struct
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CoinsMarketItem {
-    #[serde(flatten)]
-    pub basic_info: BasicCoinInfo,
+    pub id: CoinId,
+    pub symbol: String,
+    pub name: String,
     pub image: String,
-    pub current_price: Option<f64>,
-    pub market_cap: Option<f64>,
-    pub market_cap_rank: serde_json::Value,
-    pub fully_diluted_valuation: serde_json::Value,
-    pub total_volume: Option<f64>,
-    pub high_24h: Option<f64>,
-    pub low_24h: Option<f64>,
-    pub price_change_24h: Option<f64>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub current_price: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub market_cap: Option<Amount>,
+    pub market_cap_rank: Option<u32>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub fully_diluted_valuation: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub total_volume: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub high_24h: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub low_24h: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub price_change_24h: Option<Amount>,
     pub price_change_percentage_24h: Option<f64>,
-    pub market_cap_change_24h: Option<f64>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub market_cap_change_24h: Option<Amount>,
     pub market_cap_change_percentage_24h: Option<f64>,
-    pub circulating_supply: Option<f64>,
-    pub total_supply: Option<f64>,
-    pub max_supply: Option<f64>,
-    pub ath: Option<f64>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub circulating_supply: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub total_supply: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub max_supply: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub ath: Option<Amount>,
     pub ath_change_percentage: Option<f64>,
-    pub ath_date: Option<String>,
-    pub atl: Option<f64>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub ath_date: Option<DateTime>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub atl: Option<Amount>,
     pub atl_change_percentage: Option<f64>,
-    pub atl_date: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub atl_date: Option<DateTime>,
     pub roi: Option<RoiItem>,
-    pub last_updated: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_updated: Option<DateTime>,
     pub sparkline_in_7d: Option<Sparkline7days>,
     pub price_change_percentage_1h_in_currency: Option<f64>,
     pub price_change_percentage_24h_in_currency: Option<f64>,
@@ -38,157 +61,378 @@ pub struct CoinsMarketItem {
     pub price_change_percentage_30d_in_currency: Option<f64>,
     pub price_change_percentage_200d_in_currency: Option<f64>,
     pub price_change_percentage_1y_in_currency: Option<f64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CoinHistoryItem {
-    #[serde(flatten)]
-    pub basic_info: BasicCoinInfo,
-    pub localization: Localization,
+    pub id: CoinId,
+    pub symbol: String,
+    pub name: String,
+    pub localization: Option<Localization>,
     pub image: ImageItem,
     pub market_data: BasicMarketData,
     pub community_data: CommunityData,
     pub developer_data: DeveloperData,
     pub public_interest_stats: PublicInterestStats,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl CoinHistoryItem {
+    /// The name of the coin in a language, the english name if there is no translation
+    pub fn name_in(&self, language: Language) -> &str {
+        translation(self.localization.as_ref(), language).unwrap_or(&self.name)
+    }
+
+    pub(crate) fn retain_languages(&mut self, localize: &Localize) {
+        if let Some(localization) = &mut self.localization {
+            localization.retain(|code, _| localize.includes(code));
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct BasicMarketData {
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub current_price: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub market_cap: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub total_volume: AllCurrencies,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StatusUpdateItem {
-    description: Option<String>,
-    category: Option<String>,
-    created_at: Option<String>,
-    user: Option<String>,
-    user_title: Option<String>,
-    pin: bool,
-    project: Project,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub created_at: Option<DateTime>,
+    pub user: Option<String>,
+    pub user_title: Option<String>,
+    pub pin: bool,
+    pub project: Project,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Project {
-    #[serde(flatten)]
-    basic_info: BasicCoinInfo,
+    pub id: CoinId,
+    pub symbol: String,
+    pub name: String,
     #[serde(rename = "type")]
-    project_type: String,
-    image: ImageItem,
+    pub project_type: String,
+    pub image: ImageItem,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PublicInterestStats {
     pub alexa_rank: Option<f64>,
     pub bing_matches: Option<f64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Sparkline7days {
-    pub price: Vec<f64>,
+    #[serde_as(as = "Vec<ExactAmount>")]
+    pub price: Vec<Amount>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct RoiItem {
     pub times: Option<f64>,
     pub currency: String,
     pub percentage: Option<f64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Ticker {
     pub base: String,
     pub target: String,
     pub market: Market,
-    pub last: f64,
-    pub volume: f64,
-    pub converted_last: HashMap<String, f64>,
-    pub converted_volume: HashMap<String, f64>,
-    pub cost_to_move_up_usd: Option<f64>,
-    pub cost_to_move_down_usd: Option<f64>,
+    #[serde_as(as = "ExactAmount")]
+    pub last: Amount,
+    #[serde_as(as = "ExactAmount")]
+    pub volume: Amount,
+    #[serde_as(as = "HashMap<_, ExactAmount>")]
+    pub converted_last: HashMap<String, Amount>,
+    #[serde_as(as = "HashMap<_, ExactAmount>")]
+    pub converted_volume: HashMap<String, Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub cost_to_move_up_usd: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub cost_to_move_down_usd: Option<Amount>,
     pub trust_score: Option<String>,
     pub bid_ask_spread_percentage: Option<f64>,
-    pub timestamp: Option<String>,
-    pub last_traded_at: Option<String>,
-    pub last_fetch_at: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub timestamp: Option<DateTime>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_traded_at: Option<DateTime>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_fetch_at: Option<DateTime>,
     pub is_anomaly: bool,
     pub is_stale: bool,
     pub trade_url: Option<String>,
     pub token_info_url: Option<String>,
-    pub coin_id: String,
-    pub target_coin_id: Option<String>,
+    pub coin_id: CoinId,
+    pub target_coin_id: Option<CoinId>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// One page of the tickers of a coin or an exchange, see [crate::client::GeckoClient::coins_tickers]
+/// and [crate::client::GeckoClient::exchanges_tickers]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Tickers {
+    pub name: String,
+    pub tickers: Vec<Ticker>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Market {
     pub name: String,
-    pub identifier: String,
+    pub identifier: ExchangeId,
     pub has_trading_incentive: bool,
     pub logo: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// Moddels the MarketChart Data as Arrays of Tuples (unix_timestamp, value)
-#[derive(Debug, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct MarketChart {
-    pub prices: Vec<(i64, Option<f64>)>,
-    pub market_caps: Vec<(i64, Option<f64>)>,
-    pub total_volumes: Vec<(i64, Option<f64>)>,
+    #[serde_as(as = "Vec<(_, Option<ExactAmount>)>")]
+    pub prices: Vec<(i64, Option<Amount>)>,
+    #[serde_as(as = "Vec<(_, Option<ExactAmount>)>")]
+    pub market_caps: Vec<(i64, Option<Amount>)>,
+    #[serde_as(as = "Vec<(_, Option<ExactAmount>)>")]
+    pub total_volumes: Vec<(i64, Option<Amount>)>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/// Tuple representing Candle Data (time, open, high, low, close)
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+impl MarketChart {
+    /// The granularity the API returned, taken from the median spacing of the prices.
+    /// None with less than two prices
+    pub fn granularity(&self) -> Option<Granularity> {
+        let mut steps = self
+            .prices
+            .windows(2)
+            .map(|pair| pair[1].0 - pair[0].0)
+            .collect::<Vec<_>>();
+        if steps.is_empty() {
+            return None;
+        }
+        steps.sort_unstable();
+
+        let median = steps[steps.len() / 2];
+        let granularity = if median < 30 * 60 * 1000 {
+            Granularity::FiveMinutes
+        } else if median < 12 * 60 * 60 * 1000 {
+            Granularity::Hourly
+        } else {
+            Granularity::Daily
+        };
+        Some(granularity)
+    }
+}
+
+/// Candle Data of the /coins/{id}/ohlc endpoint, sent as an array `[time, open, high, low, close]`
+/// with the time in unix milliseconds marking the close of the candle
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(from = "CandleRow", into = "CandleRow")]
 pub struct Candle {
-    pub time: i64,
-    pub open: i64,
-    pub high: i64,
-    pub low: i64,
-    pub close: i64,
+    pub time: DateTime,
+    pub open: Amount,
+    pub high: Amount,
+    pub low: Amount,
+    pub close: Amount,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+struct CandleRow(
+    #[serde_as(as = "UnixMillis")] DateTime,
+    #[serde_as(as = "ExactAmount")] Amount,
+    #[serde_as(as = "ExactAmount")] Amount,
+    #[serde_as(as = "ExactAmount")] Amount,
+    #[serde_as(as = "ExactAmount")] Amount,
+);
+
+impl From<CandleRow> for Candle {
+    fn from(CandleRow(time, open, high, low, close): CandleRow) -> Self {
+        Candle {
+            time,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+impl From<Candle> for CandleRow {
+    fn from(candle: Candle) -> Self {
+        CandleRow(
+            candle.time,
+            candle.open,
+            candle.high,
+            candle.low,
+            candle.close,
+        )
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct AssetPlatform {
-    pub id: String,
+    pub id: AssetPlatformId,
     pub chain_identifier: Option<i64>,
     pub name: String,
     pub shortname: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-/*
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Contract {
-    pub id: String,
-    pub symbol: String,
-    pub name: String,
-    pub asset_platform_id: String,
-    pub platforms: Option<HashMap<String, Option<String>>>,
-    pub block_time_in_minutes: i64,
-    pub hashing_algorithm: serde_json::Value,
-    pub categories: Vec<String>,
-    pub public_notice: serde_json::Value,
-    pub additional_notices: Vec<serde_json::Value>,
-    pub localization: Localization,
-    pub description: Description,
-    pub links: LinksItem,
-    pub image: ImageItem,
-    pub country_origin: String,
-    pub genesis_date: serde_json::Value,
-    pub contract_address: String,
-    pub sentiment_votes_up_percentage: f64,
-    pub sentiment_votes_down_percentage: f64,
-    pub market_cap_rank: i64,
-    pub coingecko_rank: i64,
-    pub coingecko_score: f64,
-    pub developer_score: i64,
-    pub community_score: f64,
-    pub liquidity_score: f64,
-    pub public_interest_score: f64,
-    pub market_data: MarketData,
-    pub community_data: CommunityData,
-    pub developer_data: DeveloperData,
-    pub public_interest_stats: PublicInterestStats,
-    pub status_updates: Vec<serde_json::Value>,
-    pub last_updated: String,
-    pub tickers: Vec<Ticker>,*/
-//}
+/// Coin data of a token looked up by its contract address with [crate::client::GeckoClient::contract].
+/// The API answers with the same data as /coins/{id}, `asset_platform_id` and `contract_address` are always set
+pub type Contract = crate::model::coins::CoinsItem;
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::model::apimodels::{Candle, MarketChart};
+    use crate::model::common::Amount;
+    use crate::model::queryparams::Granularity;
+
+    const MICRO_PRICED_CHART: &str = r#"{
+        "prices": [[1672531200000, 0.000000012345678901234]],
+        "market_caps": [[1672531200000, null]],
+        "total_volumes": [[1672531200000, 16625.08390000001]]
+    }"#;
+
+    #[test]
+    fn market_chart_amounts() {
+        let chart: MarketChart = serde_json::from_str(MICRO_PRICED_CHART).unwrap();
+
+        assert_eq!(
+            chart.prices,
+            vec![(
+                1672531200000,
+                Some(Amount::from_str("0.000000012345678901234").unwrap())
+            )]
+        );
+        assert_eq!(chart.market_caps, vec![(1672531200000, None)]);
+    }
+
+    #[test]
+    fn candles_from_arrays() {
+        let json = r#"[[1672531200000, 16547.91, 16560.5, 16541.02, 16552.13]]"#;
+        let candles: Vec<Candle> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(candles[0].high, Amount::from_str("16560.5").unwrap());
+        assert_eq!(candles[0].close, Amount::from_str("16552.13").unwrap());
+        assert_eq!(
+            serde_json::from_str::<Vec<Candle>>(&serde_json::to_string(&candles).unwrap()).unwrap(),
+            candles
+        );
+        assert_eq!(
+            serde_json::to_value(&candles).unwrap()[0][0],
+            1672531200000i64
+        );
+    }
+
+    #[test]
+    fn market_chart_granularity() {
+        let chart = |times: &[i64]| {
+            let mut chart = MarketChart::default();
+            chart
+                .prices
+                .extend(times.iter().map(|time| (*time, Some(Amount::from(1u8)))));
+            chart
+        };
+        let hour = 60 * 60 * 1000;
+
+        assert_eq!(chart(&[0]).granularity(), None);
+        assert_eq!(
+            chart(&[0, 300_000, 600_000, 900_000]).granularity(),
+            Some(Granularity::FiveMinutes)
+        );
+        assert_eq!(
+            chart(&[0, hour, 2 * hour, 2 * hour + 60_000]).granularity(),
+            Some(Granularity::Hourly)
+        );
+        assert_eq!(
+            chart(&[0, 24 * hour, 48 * hour, 48 * hour + 1_000]).granularity(),
+            Some(Granularity::Daily)
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_amounts_are_exact() {
+        let chart: MarketChart = serde_json::from_str(MICRO_PRICED_CHART).unwrap();
+
+        assert_eq!(
+            chart.prices[0].1,
+            Some(Amount::from_str("0.000000012345678901234").unwrap())
+        );
+        assert_eq!(
+            chart.total_volumes[0].1,
+            Some(Amount::from_str("16625.08390000001").unwrap())
+        );
+        assert_eq!(
+            serde_json::from_str::<MarketChart>(&serde_json::to_string(&chart).unwrap()).unwrap(),
+            chart
+        );
+    }
+
+    /// More digits than an f64 holds, the models of the `extra-fields` feature only see the f64
+    #[cfg(all(feature = "decimal", not(feature = "extra-fields")))]
+    #[test]
+    fn long_decimal_amounts_round_trip() {
+        let json = r#"[[1672531200000,123456789.0123456789012,2.00000000000000000001,1.1,0.000000000000000000123456789]]"#;
+        let candles: Vec<Candle> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            candles[0].open,
+            Amount::from_str("123456789.0123456789012").unwrap()
+        );
+        assert_eq!(
+            candles[0].high,
+            Amount::from_str("2.00000000000000000001").unwrap()
+        );
+        assert_eq!(serde_json::to_string(&candles).unwrap(), json);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::model::common::{Amount, ExactAmount};
+use crate::model::identifiers::{CategoryId, CoinId};
+use crate::model::timestamp::{DateTime, Rfc3339};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Entry of /coins/categories/list
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct CategoryListing {
+    pub category_id: CategoryId,
+    pub name: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+/// Market data of a coin category from /coins/categories
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Category {
+    pub id: CategoryId,
+    pub name: String,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub market_cap: Option<Amount>,
+    /// Change of the market cap in the last 24 hours in percent
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub market_cap_change_24h: Option<Amount>,
+    pub content: Option<String>,
+    /// Ids of the three largest coins of the category
+    pub top_3_coins_id: Option<Vec<CoinId>>,
+    /// Image urls of the three largest coins of the category
+    pub top_3_coins: Option<Vec<String>>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub volume_24h: Option<Amount>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub updated_at: Option<DateTime>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
@@ -1,21 +1,30 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
-use crate::model::apimodels::{PublicInterestStats, Sparkline7days, StatusUpdateItem, Ticker};
+use crate::model::apimodels::{
+    AssetPlatform, PublicInterestStats, Sparkline7days, StatusUpdateItem, Ticker,
+};
 use crate::model::common::{
-    AllCurrencies, Ath, AthChangePercentage, AthDate, Atl, AtlChangePercentage, AtlDate,
-    CommunityData, Description, DeveloperData, FullyDilutedValuation, High24H, ImageItem,
-    LinksItem, Localization, Low24H, PriceChange24HInCurrency,
+    AllCurrencies, Amount, CommunityData, Description, DeveloperData, ExactAmount, ImageItem,
+    LinksItem, Localization,
 };
+use crate::model::description;
+use crate::model::identifiers::{AssetPlatformId, CoinId, ContractAddress};
+use crate::model::queryparams::{Language, Localize};
+use crate::model::timestamp::{Date, DateTime, IsoDate, Rfc3339};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CoinsItem {
-    pub id: String,
+    pub id: CoinId,
     pub symbol: String,
     pub name: String,
-    pub asset_platform_id: Option<String>,
-    pub platforms: Option<HashMap<String, Option<String>>>,
+    pub asset_platform_id: Option<AssetPlatformId>,
+    pub platforms: Option<HashMap<AssetPlatformId, Option<ContractAddress>>>,
+    /// Contract and token decimals per asset platform. Native coins have a single entry with an empty id and address
+    pub detail_platforms: Option<HashMap<AssetPlatformId, DetailPlatform>>,
     pub block_time_in_minutes: f64,
     pub hashing_algorithm: Option<String>,
     pub categories: Vec<String>,
@@ -26,57 +35,186 @@ pub struct CoinsItem {
     pub links: LinksItem,
     pub image: ImageItem,
     pub country_origin: Option<String>,
-    pub genesis_date: Option<String>,
-    pub contract_address: Option<String>,
-    pub sentiment_votes_up_percentage: Option<f32>,
-    pub sentiment_votes_down_percentage: Option<f32>,
+    #[serde_as(as = "Option<IsoDate>")]
+    pub genesis_date: Option<Date>,
+    pub contract_address: Option<ContractAddress>,
+    pub sentiment_votes_up_percentage: Option<f64>,
+    pub sentiment_votes_down_percentage: Option<f64>,
     pub market_cap_rank: Option<i32>,
-    pub coingecko_rank: Option<f32>,
-    pub coingecko_score: Option<f32>,
-    pub developer_score: Option<f32>,
-    pub community_score: Option<f32>,
-    pub liquidity_score: Option<f32>,
-    pub public_interest_score: Option<f32>,
+    pub coingecko_rank: Option<f64>,
+    pub coingecko_score: Option<f64>,
+    pub developer_score: Option<f64>,
+    pub community_score: Option<f64>,
+    pub liquidity_score: Option<f64>,
+    pub public_interest_score: Option<f64>,
     pub market_data: Option<MarketData>,
     pub community_data: Option<CommunityData>,
     pub developer_data: Option<DeveloperData>,
     pub public_interest_stats: PublicInterestStats,
     pub status_updates: Vec<StatusUpdateItem>,
-    pub last_updated: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_updated: Option<DateTime>,
     pub tickers: Option<Vec<Ticker>>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+/// Deployment of a token on an asset platform
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DetailPlatform {
+    /// Decimals of the token contract, None if Coingecko does not know them
+    pub decimal_place: Option<u8>,
+    pub contract_address: ContractAddress,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+impl DetailPlatform {
+    /// Scales a raw on-chain balance by the decimals of the token, eg. 1500000 with 6 decimals is 1.5.
+    /// Returns None if the decimals are not known.
+    ///
+    /// With the `decimal` feature it is also None if the balance does not fit a [rust_decimal::Decimal],
+    /// that is a raw balance of 2^96 or more, or more than 28 decimals.
+    /// [DetailPlatform::decimal_place] tells the two cases apart.
+    pub fn to_amount(&self, raw: u128) -> Option<Amount> {
+        let decimals = self.decimal_place?;
+
+        #[cfg(not(feature = "decimal"))]
+        return Some(raw as f64 / 10f64.powi(decimals.into()));
+
+        #[cfg(feature = "decimal")]
+        return rust_decimal::Decimal::try_from_i128_with_scale(
+            i128::try_from(raw).ok()?,
+            decimals.into(),
+        )
+        .ok()
+        .map(|amount| amount.normalize());
+    }
+}
+
+impl CoinsItem {
+    /// The deployments of the token on asset platforms, empty for native coins
+    pub fn deployments(&self) -> impl Iterator<Item = (&AssetPlatformId, &DetailPlatform)> {
+        self.detail_platforms
+            .iter()
+            .flatten()
+            .filter(|(id, detail)| {
+                !id.as_str().is_empty() && !detail.contract_address.as_str().is_empty()
+            })
+    }
+
+    /// The deployment of the token on an asset platform
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// async fn usdc_on_polygon() {
+    ///     let client = GeckoClient::default();
+    ///     let platforms = client.assetplatforms(None).await.unwrap();
+    ///     let polygon = platforms.iter().find(|platform| platform.id == "polygon-pos").unwrap();
+    ///     let usdc = client.coins_short("usd-coin").await.unwrap();
+    ///     if let Some(deployment) = usdc.deployment_on(polygon) {
+    ///         let balance = deployment.to_amount(1_500_000);
+    ///     }
+    /// }
+    /// ```
+    pub fn deployment_on(&self, platform: &AssetPlatform) -> Option<&DetailPlatform> {
+        self.deployments()
+            .find(|(id, _)| **id == platform.id)
+            .map(|(_, detail)| detail)
+    }
+
+    /// The name of the coin in a language, the english name if there is no translation
+    pub fn name_in(&self, language: Language) -> &str {
+        translation(self.localization.as_ref(), language).unwrap_or(&self.name)
+    }
+
+    /// The description in the first language of the fallback chain that has one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// use rustgecko::model::queryparams::Language;
+    /// async fn german_description() {
+    ///     let bitcoin = GeckoClient::default().coins_short("bitcoin").await.unwrap();
+    ///     let description = bitcoin.description_in(&[Language::De, Language::En]);
+    /// }
+    /// ```
+    pub fn description_in(&self, languages: &[Language]) -> Option<&str> {
+        languages.iter().find_map(|language| {
+            self.description
+                .get(language.code())
+                .and_then(Option::as_deref)
+                .filter(|description| !description.trim().is_empty())
+        })
+    }
+
+    /// The description in the first language of the fallback chain as plain text, see [description::to_plain_text]
+    pub fn description_text(&self, languages: &[Language]) -> Option<String> {
+        self.description_in(languages)
+            .map(description::to_plain_text)
+    }
+
+    /// The description in the first language of the fallback chain as Markdown, see [description::to_markdown]
+    pub fn description_markdown(&self, languages: &[Language]) -> Option<String> {
+        self.description_in(languages).map(description::to_markdown)
+    }
+
+    pub(crate) fn retain_languages(&mut self, localize: &Localize) {
+        if let Some(localization) = &mut self.localization {
+            localization.retain(|code, _| localize.includes(code));
+        }
+        self.description.retain(|code, _| localize.includes(code));
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// The non-empty translation for a language
+pub(crate) fn translation(localization: Option<&Localization>, language: Language) -> Option<&str> {
+    localization?
+        .get(language.code())
+        .map(String::as_str)
+        .filter(|name| !name.trim().is_empty())
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MarketData {
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub current_price: AllCurrencies,
     pub total_value_locked: serde_json::Value,
     pub mcap_to_tvl_ratio: serde_json::Value,
     pub fdv_to_tvl_ratio: serde_json::Value,
     ////TODO: Put RoiItem here ?
     pub roi: serde_json::Value,
-    #[serde(flatten)]
-    pub ath: Ath,
-    #[serde(flatten)]
-    pub ath_change_percentage: AthChangePercentage,
-    #[serde(flatten)]
-    pub ath_date: AthDate,
-    #[serde(flatten)]
-    pub atl: Atl,
-    #[serde(flatten)]
-    pub atl_change_percentage: AtlChangePercentage,
-    #[serde(flatten)]
-    pub atl_date: AtlDate,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub ath: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub ath_change_percentage: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Rfc3339>")]
+    pub ath_date: HashMap<String, DateTime>,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub atl: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub atl_change_percentage: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Rfc3339>")]
+    pub atl_date: HashMap<String, DateTime>,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub market_cap: AllCurrencies,
-    #[serde(flatten)]
-    pub market_cap_rank: serde_json::Value,
-    #[serde(flatten)]
-    pub fully_diluted_valuation: FullyDilutedValuation,
+    pub market_cap_rank: Option<u32>,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub fully_diluted_valuation: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
     pub total_volume: AllCurrencies,
-    #[serde(flatten)]
-    pub high_24h: High24H,
-    #[serde(flatten)]
-    pub low_24h: Low24H,
-    pub price_change_24h: Option<f64>,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub high_24h: AllCurrencies,
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub low_24h: AllCurrencies,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub price_change_24h: Option<Amount>,
     pub price_change_percentage_24h: Option<f64>,
     pub price_change_percentage_7d: Option<f64>,
     pub price_change_percentage_14d: Option<f64>,
@@ -84,23 +222,288 @@ pub struct MarketData {
     pub price_change_percentage_60d: Option<f64>,
     pub price_change_percentage_200d: Option<f64>,
     pub price_change_percentage_1y: Option<f64>,
-    pub market_cap_change_24h: Option<f64>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub market_cap_change_24h: Option<Amount>,
     pub market_cap_change_percentage_24h: Option<f64>,
-    #[serde(flatten)]
-    pub price_change_24h_in_currency: Option<PriceChange24HInCurrency>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
+    pub price_change_24h_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_1h_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_24h_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_7d_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_14d_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_30d_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_60d_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_200d_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub price_change_percentage_1y_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub market_cap_change_24h_in_currency: Option<AllCurrencies>,
+    #[serde_as(as = "Option<HashMap<_, Option<ExactAmount>>>")]
     pub market_cap_change_percentage_24h_in_currency: Option<AllCurrencies>,
-    pub total_supply: serde_json::Value,
-    pub max_supply: serde_json::Value,
-    pub circulating_supply: serde_json::Value,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub total_supply: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub max_supply: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub circulating_supply: Option<Amount>,
     pub sparkline_7d: Option<Sparkline7days>,
-    pub last_updated: Option<String>,
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_updated: Option<DateTime>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+/// Market data of a coin in a single currency, see [MarketData::in_currency]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencySnapshot {
+    pub currency: String,
+    pub price: Option<Amount>,
+    pub market_cap: Option<Amount>,
+    pub total_volume: Option<Amount>,
+    pub fully_diluted_valuation: Option<Amount>,
+    pub ath: Option<Amount>,
+    pub ath_change_percentage: Option<Amount>,
+    pub ath_date: Option<DateTime>,
+    pub atl: Option<Amount>,
+    pub atl_change_percentage: Option<Amount>,
+    pub atl_date: Option<DateTime>,
+    pub high_24h: Option<Amount>,
+    pub low_24h: Option<Amount>,
+    pub price_change_24h: Option<Amount>,
+    pub price_change_percentage_1h: Option<Amount>,
+    pub price_change_percentage_24h: Option<Amount>,
+    pub price_change_percentage_7d: Option<Amount>,
+    pub price_change_percentage_14d: Option<Amount>,
+    pub price_change_percentage_30d: Option<Amount>,
+    pub price_change_percentage_60d: Option<Amount>,
+    pub price_change_percentage_200d: Option<Amount>,
+    pub price_change_percentage_1y: Option<Amount>,
+    pub market_cap_change_24h: Option<Amount>,
+    pub market_cap_change_percentage_24h: Option<Amount>,
+}
+
+impl MarketData {
+    /// The currencies the market data is available in (usd, eur, btc, ...)
+    pub fn currencies(&self) -> impl Iterator<Item = &str> {
+        self.current_price.keys().map(String::as_str)
+    }
+
+    /// Collects all values of the market data in the given currency (usd, eur, jpy, etc.).
+    /// Returns None if there is no price in that currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// async fn bitcoin_ath_in_eur() {
+    ///     let bitcoin = GeckoClient::default().coins_short("bitcoin").await.unwrap();
+    ///     let eur = bitcoin.market_data.unwrap().in_currency("eur").unwrap();
+    ///     println!("ATH {:?} on {:?}", eur.ath, eur.ath_date);
+    /// }
+    /// ```
+    pub fn in_currency(&self, currency: &str) -> Option<CurrencySnapshot> {
+        let currency = currency.to_lowercase();
+        if !self.current_price.contains_key(&currency) {
+            return None;
+        }
+
+        let value = |values: &AllCurrencies| values.get(&currency).copied().flatten();
+        let optional = |values: &Option<AllCurrencies>| values.as_ref().and_then(value);
+
+        Some(CurrencySnapshot {
+            price: value(&self.current_price),
+            market_cap: value(&self.market_cap),
+            total_volume: value(&self.total_volume),
+            fully_diluted_valuation: value(&self.fully_diluted_valuation),
+            ath: value(&self.ath),
+            ath_change_percentage: value(&self.ath_change_percentage),
+            ath_date: self.ath_date.get(&currency).copied(),
+            atl: value(&self.atl),
+            atl_change_percentage: value(&self.atl_change_percentage),
+            atl_date: self.atl_date.get(&currency).copied(),
+            high_24h: value(&self.high_24h),
+            low_24h: value(&self.low_24h),
+            price_change_24h: optional(&self.price_change_24h_in_currency),
+            price_change_percentage_1h: optional(&self.price_change_percentage_1h_in_currency),
+            price_change_percentage_24h: optional(&self.price_change_percentage_24h_in_currency),
+            price_change_percentage_7d: optional(&self.price_change_percentage_7d_in_currency),
+            price_change_percentage_14d: optional(&self.price_change_percentage_14d_in_currency),
+            price_change_percentage_30d: optional(&self.price_change_percentage_30d_in_currency),
+            price_change_percentage_60d: optional(&self.price_change_percentage_60d_in_currency),
+            price_change_percentage_200d: optional(&self.price_change_percentage_200d_in_currency),
+            price_change_percentage_1y: optional(&self.price_change_percentage_1y_in_currency),
+            market_cap_change_24h: optional(&self.market_cap_change_24h_in_currency),
+            market_cap_change_percentage_24h: optional(
+                &self.market_cap_change_percentage_24h_in_currency,
+            ),
+            currency,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use std::collections::HashMap;
+
+    use crate::model::coins::{translation, DetailPlatform, MarketData};
+    use crate::model::common::Amount;
+    use crate::model::identifiers::{AssetPlatformId, ContractAddress};
+    use crate::model::queryparams::{Language, Localize};
+
+    const MARKET_DATA: &str = r#"{
+        "current_price": {"usd": 67512, "eur": 62310.5},
+        "total_value_locked": null,
+        "mcap_to_tvl_ratio": null,
+        "fdv_to_tvl_ratio": null,
+        "roi": null,
+        "ath": {"usd": 73738, "eur": 67554},
+        "ath_change_percentage": {"usd": -8.44, "eur": -7.76},
+        "ath_date": {"usd": "2024-03-14T07:10:36.635Z", "eur": "2024-03-14T07:10:36.635Z"},
+        "atl": {"usd": 67.81, "eur": 51.3},
+        "atl_change_percentage": {"usd": 99450.1, "eur": 121380.2},
+        "atl_date": {"usd": "2013-07-06T00:00:00.000Z", "eur": "2013-07-05T00:00:00.000Z"},
+        "market_cap": {"usd": 1329000000000, "eur": 1227000000000},
+        "market_cap_rank": 1,
+        "fully_diluted_valuation": {"usd": 1417000000000, "eur": 1308000000000},
+        "total_volume": {"usd": 29650000000, "eur": 27360000000},
+        "high_24h": {"usd": 68100, "eur": 62900},
+        "low_24h": {"usd": 66210, "eur": 61100},
+        "price_change_24h": 1123.5,
+        "price_change_percentage_24h": 1.69,
+        "price_change_percentage_7d": 4.1,
+        "price_change_percentage_14d": 6.2,
+        "price_change_percentage_30d": 12.8,
+        "price_change_percentage_60d": 20.3,
+        "price_change_percentage_200d": 45.2,
+        "price_change_percentage_1y": 130.7,
+        "market_cap_change_24h": 22100000000,
+        "market_cap_change_percentage_24h": 1.7,
+        "price_change_24h_in_currency": {"usd": 1123.5, "eur": 1010.2},
+        "price_change_percentage_1h_in_currency": {"usd": 0.12, "eur": 0.1},
+        "price_change_percentage_24h_in_currency": {"usd": 1.69, "eur": 1.65},
+        "price_change_percentage_7d_in_currency": {"usd": 4.1, "eur": 3.9},
+        "price_change_percentage_14d_in_currency": {"usd": 6.2},
+        "price_change_percentage_30d_in_currency": {"usd": 12.8},
+        "price_change_percentage_60d_in_currency": {"usd": 20.3},
+        "price_change_percentage_200d_in_currency": {"usd": 45.2},
+        "price_change_percentage_1y_in_currency": {"usd": 130.7, "eur": 125.1},
+        "market_cap_change_24h_in_currency": {"usd": 22100000000, "eur": 20400000000},
+        "market_cap_change_percentage_24h_in_currency": {"usd": 1.7, "eur": 1.69},
+        "total_supply": 21000000,
+        "max_supply": 21000000,
+        "circulating_supply": 19687000,
+        "last_updated": "2024-04-20T10:15:03.513Z"
+    }"#;
+
+    fn amount(value: &str) -> Option<Amount> {
+        Some(Amount::from_str(value).unwrap())
+    }
+
+    #[test]
+    fn in_currency_collects_all_values() {
+        let market_data: MarketData = serde_json::from_str(MARKET_DATA).unwrap();
+        let eur = market_data.in_currency("EUR").unwrap();
+
+        assert_eq!(eur.currency, "eur");
+        assert_eq!(eur.price, amount("62310.5"));
+        assert_eq!(eur.ath, amount("67554"));
+        assert_eq!(eur.ath_date, market_data.ath_date.get("eur").copied());
+        assert_eq!(eur.atl, amount("51.3"));
+        assert_eq!(eur.fully_diluted_valuation, amount("1308000000000"));
+        assert_eq!(eur.high_24h, amount("62900"));
+        assert_eq!(eur.low_24h, amount("61100"));
+        assert_eq!(eur.price_change_24h, amount("1010.2"));
+        assert_eq!(eur.price_change_percentage_1y, amount("125.1"));
+        assert_eq!(eur.price_change_percentage_30d, None);
+        assert_eq!(market_data.market_cap_rank, Some(1));
+    }
+
+    #[test]
+    fn in_currency_without_price_is_none() {
+        let market_data: MarketData = serde_json::from_str(MARKET_DATA).unwrap();
+
+        assert!(market_data.in_currency("jpy").is_none());
+        assert_eq!(market_data.currencies().count(), 2);
+    }
+
+    #[test]
+    fn translations_fall_back() {
+        let localization = HashMap::from([
+            ("en".to_string(), "Bitcoin".to_string()),
+            ("ja".to_string(), "ビットコイン".to_string()),
+            ("de".to_string(), "".to_string()),
+        ]);
+
+        assert_eq!(
+            translation(Some(&localization), Language::Ja),
+            Some("ビットコイン")
+        );
+        assert_eq!(translation(Some(&localization), Language::De), None);
+        assert_eq!(translation(None, Language::Ja), None);
+    }
+
+    #[test]
+    fn localize_keeps_english() {
+        let only = Localize::Only(vec![Language::ZhTw]);
+
+        assert!(only.includes("zh-tw"));
+        assert!(only.includes("en"));
+        assert!(!only.includes("zh"));
+        assert!(!Localize::None.includes("de"));
+        assert_eq!(only.to_string(), "true");
+    }
+
+    #[test]
+    fn detail_platforms_scale_balances() {
+        let platforms: HashMap<AssetPlatformId, DetailPlatform> = serde_json::from_str(
+            r#"{
+                "": {"decimal_place": null, "contract_address": ""},
+                "ethereum": {"decimal_place": 6, "contract_address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"},
+                "solana": {"decimal_place": null, "contract_address": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"}
+            }"#,
+        )
+        .unwrap();
+
+        let ethereum = &platforms[&AssetPlatformId::new("ethereum")];
+        assert_eq!(
+            ethereum.contract_address,
+            ContractAddress::new("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+        );
+        assert_eq!(
+            ethereum.to_amount(1_500_000),
+            Some(Amount::from_str("1.5").unwrap())
+        );
+        assert_eq!(
+            platforms[&AssetPlatformId::new("solana")].to_amount(1_500_000),
+            None
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn balances_beyond_a_decimal_are_none() {
+        let platform = |decimal_place| DetailPlatform {
+            decimal_place: Some(decimal_place),
+            contract_address: ContractAddress::new("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        };
+
+        assert_eq!(platform(18).to_amount(1 << 96), None);
+        assert_eq!(platform(29).to_amount(1), None);
+        assert_eq!(
+            platform(18).to_amount((1 << 96) - 1),
+            Some(Amount::from_str("79228162514.264337593543950335").unwrap())
+        );
+    }
 }
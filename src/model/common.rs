@@ -1,14 +1,132 @@
 use std::collections::HashMap;
+use std::ops::Deref;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
 
-pub type Price = HashMap<String, f64>;
+use crate::model::identifiers::CoinId;
+
+/// Prices, volumes and supplies. [f64] by default, with the `decimal` feature
+/// [rust_decimal::Decimal] parsed from the text of the number in the response, see [ExactAmount]
+#[cfg(not(feature = "decimal"))]
+pub type Amount = f64;
+/// Prices, volumes and supplies. [f64] by default, with the `decimal` feature
+/// [rust_decimal::Decimal] parsed from the text of the number in the response, see [ExactAmount]
+#[cfg(feature = "decimal")]
+pub type Amount = rust_decimal::Decimal;
+
+/// Serializes an [Amount] as the text of its JSON number, eg. `#[serde_as(as = "Option<ExactAmount>")]`.
+///
+/// With the `decimal` feature the [rust_decimal::Decimal] is parsed from the digits of the response and written back
+/// with all of them, up to the 28 significant digits a Decimal holds. Numbers that serde has already buffered,
+/// like the fields of a model with the `extra-fields` feature, only arrive as [f64] and are parsed from that.
+/// Without the feature it is a plain [f64].
+pub struct ExactAmount;
+
+#[cfg(not(feature = "decimal"))]
+impl SerializeAs<Amount> for ExactAmount {
+    fn serialize_as<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+        amount.serialize(serializer)
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+impl<'de> DeserializeAs<'de, Amount> for ExactAmount {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        Amount::deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl SerializeAs<Amount> for ExactAmount {
+    fn serialize_as<S: Serializer>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_json::value::RawValue::from_string(amount.to_string())
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<'de> DeserializeAs<'de, Amount> for ExactAmount {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Amount, D::Error> {
+        // the name serde_json hands the text of the next value to, instead of parsing it
+        const RAW_VALUE: &str = "$serde_json::private::RawValue";
+
+        deserializer.deserialize_newtype_struct(RAW_VALUE, exact::AmountVisitor)
+    }
+}
+
+#[cfg(feature = "decimal")]
+mod exact {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::de::{Error, MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer};
+
+    use crate::model::common::Amount;
+
+    pub(super) struct AmountVisitor;
+
+    impl<'de> Visitor<'de> for AmountVisitor {
+        type Value = Amount;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a number")
+        }
+
+        /// serde_json sends the text as the only entry of a map
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Amount, A::Error> {
+            map.next_key::<String>()?
+                .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            let text = map.next_value::<String>()?;
+
+            Amount::from_str(&text)
+                .or_else(|_| Amount::from_scientific(&text))
+                .map_err(|_| A::Error::invalid_value(serde::de::Unexpected::Str(&text), &self))
+        }
+
+        /// Any other deserializer passes the number as it is
+        fn visit_newtype_struct<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Amount, D::Error> {
+            <Amount as Deserialize>::deserialize(deserializer)
+        }
+    }
+}
+
+pub type Price = HashMap<String, Amount>;
 pub type Localization = HashMap<String, String>;
-pub type AllCurrencies = HashMap<String, Option<f64>>;
+pub type AllCurrencies = HashMap<String, Option<Amount>>;
 pub type SupportedVsCurrencies = Vec<String>;
 pub type Description = HashMap<String, Option<String>>;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Fields of a response that are not part of the model (yet).
+/// Every model has them in its `extra` field when the `extra-fields` feature is enabled.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Extra(pub HashMap<String, serde_json::Value>);
+
+impl Serialize for Extra {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if crate::drift::skip_extra_fields() {
+            serializer.collect_map(std::iter::empty::<(&str, &serde_json::Value)>())
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl Deref for Extra {
+    type Target = HashMap<String, serde_json::Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct LinksItem {
     pub homepage: Vec<Option<String>>,
     pub blockchain_site: Vec<Option<String>>,
@@ -21,78 +139,39 @@ pub struct LinksItem {
     pub telegram_channel_identifier: Option<String>,
     pub subreddit_url: Option<String>,
     pub repos_url: ReposUrl,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ReposUrl {
     pub github: Vec<Option<String>>,
     pub bitbucket: Vec<Option<String>>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ImageItem {
     pub thumb: Option<String>,
     pub small: Option<String>,
     pub large: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[deprecated(note = "the models have their own id, symbol and name fields")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct BasicCoinInfo {
-    pub id: String,
+    pub id: CoinId,
     pub symbol: String,
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Ath {
-    ath: AllCurrencies,
-}
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AthChangePercentage {
-    ath_change_percentage: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AthDate {
-    ath_date: HashMap<String, String>,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Atl {
-    atl: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AtlChangePercentage {
-    atl_change_percentage: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AtlDate {
-    atl_date: HashMap<String, String>,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FullyDilutedValuation {
-    fully_diluted_valuation: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct High24H {
-    high_24h: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Low24H {
-    low_24h: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct PriceChange24HInCurrency {
-    price_change_24h_in_currency: AllCurrencies,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DeveloperData {
     pub forks: Option<f64>,
     pub stars: Option<f64>,
@@ -104,9 +183,12 @@ pub struct DeveloperData {
     pub code_additions_deletions_4_weeks: CodeAdditionsDeletions4Weeks,
     pub commit_count_4_weeks: Option<f64>,
     pub last_4_weeks_commit_activity_series: Option<Vec<f64>>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CommunityData {
     pub facebook_likes: Option<i64>,
     pub twitter_followers: Option<i64>,
@@ -114,16 +196,25 @@ pub struct CommunityData {
     pub reddit_average_comments_48h: Option<f64>,
     pub reddit_subscribers: Option<i64>,
     pub reddit_accounts_active_48h: serde_json::Value,
-    pub telegram_channel_user_count: Option<Option<i64>>,
+    pub telegram_channel_user_count: Option<i64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CodeAdditionsDeletions4Weeks {
     pub additions: Option<f64>,
     pub deletions: Option<f64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Ping {
     pub gecko_says: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: Extra,
 }
@@ -0,0 +1,307 @@
+//! Conversion of the HTML in coin descriptions to plain text and Markdown.
+//!
+//! Coingecko descriptions are short HTML fragments, mostly text with `<a>` anchors and `\r\n` line breaks.
+//! Links are kept if they point to http(s) or mailto, all other markup is dropped. The content of
+//! `<script>`, `<style>` and embedding tags is removed entirely.
+
+/// The description as plain text, links are written as `text (url)`
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::description::to_plain_text;
+/// let html = r#"Read the <a href="https://bitcoin.org/bitcoin.pdf">whitepaper</a>.&nbsp;"#;
+/// assert_eq!(to_plain_text(html), "Read the whitepaper (https://bitcoin.org/bitcoin.pdf).");
+/// ```
+pub fn to_plain_text(html: &str) -> String {
+    render(html, Format::PlainText)
+}
+
+/// The description as Markdown, links are written as `[text](url)` and Markdown syntax in the text is escaped
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::description::to_markdown;
+/// let html = r#"Read the <a href="https://bitcoin.org/bitcoin.pdf">whitepaper</a>."#;
+/// assert_eq!(to_markdown(html), "Read the [whitepaper](https://bitcoin.org/bitcoin.pdf).");
+/// ```
+pub fn to_markdown(html: &str) -> String {
+    render(html, Format::Markdown)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    PlainText,
+    Markdown,
+}
+
+/// Tags whose content is never rendered
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "template", "noscript",
+];
+
+fn render(html: &str, format: Format) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut links: Vec<(usize, Option<String>)> = Vec::new();
+    let mut skipped: Option<String> = None;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            if skipped.is_none() {
+                push_text(&mut out, rest, format);
+            }
+            break;
+        };
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            if skipped.is_none() {
+                push_text(&mut out, rest, format);
+            }
+            break;
+        };
+
+        if skipped.is_none() {
+            push_text(&mut out, &rest[..start], format);
+        }
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        if let Some(skipped_tag) = &skipped {
+            if closing && name == *skipped_tag {
+                skipped = None;
+            }
+            continue;
+        }
+
+        match name.as_str() {
+            name if SKIPPED_TAGS.contains(&name) && !closing && !tag.ends_with('/') => {
+                skipped = Some(name.to_string())
+            }
+            "br" => out.push('\n'),
+            "p" | "div" | "ul" | "ol" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                out.push_str("\n\n")
+            }
+            "li" if !closing => out.push_str("\n- "),
+            "strong" | "b" if format == Format::Markdown => out.push_str("**"),
+            "em" | "i" if format == Format::Markdown => out.push('*'),
+            "a" if !closing => links.push((out.len(), href(tag).filter(|url| is_safe(url)))),
+            "a" => {
+                if let Some((position, url)) = links.pop() {
+                    let text = out.split_off(position);
+                    push_link(&mut out, text, url, format);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    normalize_whitespace(&out)
+}
+
+fn push_text(out: &mut String, text: &str, format: Format) {
+    let text = decode_entities(text);
+
+    match format {
+        Format::PlainText => out.push_str(&text),
+        Format::Markdown => {
+            let mut line_start = out[out.rfind('\n').map_or(0, |i| i + 1)..]
+                .trim()
+                .is_empty();
+            let mut chars = text.chars().peekable();
+
+            while let Some(c) = chars.next() {
+                // an ordered list marker is a number followed by . or ) at the start of a line
+                if line_start && c.is_ascii_digit() {
+                    out.push(c);
+                    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                        out.push(digit);
+                    }
+                    if let Some(marker) = chars.next_if(|c| matches!(c, '.' | ')')) {
+                        out.push('\\');
+                        out.push(marker);
+                    }
+                    line_start = false;
+                    continue;
+                }
+
+                if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>')
+                    || (line_start && matches!(c, '#' | '-' | '+' | '='))
+                {
+                    out.push('\\');
+                }
+                out.push(c);
+
+                if c == '\n' || c == '\r' {
+                    line_start = true;
+                } else if !c.is_whitespace() {
+                    line_start = false;
+                }
+            }
+        }
+    }
+}
+
+fn push_link(out: &mut String, text: String, url: Option<String>, format: Format) {
+    let Some(url) = url else {
+        out.push_str(&text);
+        return;
+    };
+
+    match format {
+        Format::PlainText if text.trim().is_empty() || text.trim() == url => out.push_str(&url),
+        Format::PlainText => {
+            out.push_str(&format!("{} ({})", text.trim(), url));
+        }
+        Format::Markdown => {
+            let text = if text.trim().is_empty() {
+                url.as_str()
+            } else {
+                text.trim()
+            };
+            let url = url
+                .replace(' ', "%20")
+                .replace('(', "%28")
+                .replace(')', "%29");
+            out.push_str(&format!("[{}]({})", text, url));
+        }
+    }
+}
+
+/// The value of the href attribute of an anchor tag
+fn href(tag: &str) -> Option<String> {
+    let lowercase = tag.to_ascii_lowercase();
+    let position = lowercase.find("href")?;
+    let value = tag[position + 4..]
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+
+    let url = match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+        _ => value.split_whitespace().next()?,
+    };
+
+    Some(decode_entities(url.trim()))
+}
+
+fn is_safe(url: &str) -> bool {
+    let url = url.to_ascii_lowercase();
+    url.starts_with("https://") || url.starts_with("http://") || url.starts_with("mailto:")
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        match entity.and_then(decode_entity) {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[entity.map_or(0, str::len) + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Collapses runs of spaces in a line and keeps at most one empty line between paragraphs
+fn normalize_whitespace(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in text.split('\n') {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() && matches!(lines.last().map(String::as_str), None | Some("")) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::description::{to_markdown, to_plain_text};
+
+    const DESCRIPTION: &str = "Bitcoin is the first <b>decentralized</b> currency.\r\n\r\n\r\n\
+        See <a href=\"https://bitcoin.org\">bitcoin.org</a> and the \
+        <a href='https://en.wikipedia.org/wiki/Bitcoin_(disambiguation)'>wiki_page</a>.\r\n\
+        <script>alert('x')</script><a href=\"javascript:alert(1)\">Click</a> &amp; &#x1F680;";
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(
+            to_plain_text(DESCRIPTION),
+            "Bitcoin is the first decentralized currency.\n\n\
+             See bitcoin.org (https://bitcoin.org) and the \
+             wiki_page (https://en.wikipedia.org/wiki/Bitcoin_(disambiguation)).\n\
+             Click & 🚀"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            to_markdown(DESCRIPTION),
+            "Bitcoin is the first **decentralized** currency.\n\n\
+             See [bitcoin.org](https://bitcoin.org) and the \
+             [wiki\\_page](https://en.wikipedia.org/wiki/Bitcoin_%28disambiguation%29).\n\
+             Click & 🚀"
+        );
+        assert_eq!(to_markdown("1 &lt; 2 <i>*</i>"), "1 \\< 2 *\\**");
+        assert_eq!(
+            to_markdown("# Top 10\r\n- fast\r\n 2. cheap<br>+1 = 2-3"),
+            "\\# Top 10\n\\- fast\n2\\. cheap\n\\+1 = 2-3"
+        );
+        assert_eq!(
+            to_plain_text("<a href=https://x.org/a>x</a>"),
+            "x (https://x.org/a)"
+        );
+    }
+}
@@ -1,16 +1,182 @@
-use serde::{Deserialize, Serialize};
+use crate::model::common::{Amount, ExactAmount};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::serde_as;
 use std::collections::HashMap;
 
+/// Kind of unit an exchange rate is quoted in
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum RateKind {
+    Fiat,
+    Crypto,
+    Commodity,
+    /// A kind introduced by Coingecko after this version of the crate, as sent by the API
+    Other(String),
+}
+
+impl RateKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RateKind::Fiat => "fiat",
+            RateKind::Crypto => "crypto",
+            RateKind::Commodity => "commodity",
+            RateKind::Other(kind) => kind,
+        }
+    }
+}
+
+impl From<String> for RateKind {
+    fn from(kind: String) -> Self {
+        match kind.as_str() {
+            "fiat" => RateKind::Fiat,
+            "crypto" => RateKind::Crypto,
+            "commodity" => RateKind::Commodity,
+            _ => RateKind::Other(kind),
+        }
+    }
+}
+
+impl Serialize for RateKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RateKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(RateKind::from)
+    }
+}
+
+/// Value of one BTC in a unit
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct ExchangeRateItem {
-    name: String,
-    unit: String,
-    value: f64,
+    pub name: String,
+    pub unit: String,
+    #[serde_as(as = "ExactAmount")]
+    pub value: Amount,
     #[serde(rename = "type")]
-    exchange_type: Option<String>,
+    pub kind: RateKind,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
 }
 
+/// BTC-to-Currency exchange rates keyed by the ticker of the unit (usd, eth, xau, ...)
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct ExchangeRates {
-    rates: HashMap<String, ExchangeRateItem>,
+    pub rates: HashMap<String, ExchangeRateItem>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+impl ExchangeRates {
+    /// The rate of a unit by its ticker, case-insensitive
+    pub fn get(&self, unit: &str) -> Option<&ExchangeRateItem> {
+        self.rates
+            .get(unit)
+            .or_else(|| self.rates.get(&unit.to_lowercase()))
+    }
+
+    /// How many `to` one `from` is worth, computed through the BTC base
+    pub fn rate(&self, from: &str, to: &str) -> Option<Amount> {
+        self.convert(Amount::from(1u8), from, to)
+    }
+
+    /// Converts an amount between any two listed units offline, computed through the BTC base.
+    /// Returns None if one of the units is not listed or has no value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// async fn eur_to_eth() {
+    ///     let rates = GeckoClient::default().exchangerates().await.unwrap();
+    ///     let eth = rates.convert(100u8.into(), "eur", "eth");
+    /// }
+    /// ```
+    pub fn convert(&self, amount: Amount, from: &str, to: &str) -> Option<Amount> {
+        let from = self.get(from)?.value;
+        let to = self.get(to)?.value;
+
+        if from == Amount::from(0u8) {
+            return None;
+        }
+
+        // multiply first so the division only rounds once
+        #[cfg(not(feature = "decimal"))]
+        return Some(amount * to / from);
+
+        #[cfg(feature = "decimal")]
+        return amount.checked_mul(to)?.checked_div(from);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::model::common::Amount;
+    use crate::model::exchangerates::{ExchangeRates, RateKind};
+
+    const RATES: &str = r#"{
+        "rates": {
+            "btc": {"name": "Bitcoin", "unit": "BTC", "value": 1, "type": "crypto"},
+            "eth": {"name": "Ether", "unit": "ETH", "value": 20, "type": "crypto"},
+            "usd": {"name": "US Dollar", "unit": "$", "value": 64000, "type": "fiat"},
+            "eur": {"name": "Euro", "unit": "€", "value": 58000, "type": "fiat"},
+            "xau": {"name": "Gold - Troy Ounce", "unit": "XAU", "value": 0, "type": "commodity"},
+            "bits": {"name": "Bits", "unit": "μBTC", "value": 1000000, "type": "satoshi"}
+        }
+    }"#;
+
+    fn amount(value: &str) -> Amount {
+        Amount::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn rate_kinds() {
+        let rates: ExchangeRates = serde_json::from_str(RATES).unwrap();
+
+        assert_eq!(rates.get("usd").unwrap().kind, RateKind::Fiat);
+        assert_eq!(rates.get("XAU").unwrap().kind, RateKind::Commodity);
+        assert_eq!(
+            rates.get("bits").unwrap().kind,
+            RateKind::Other("satoshi".to_string())
+        );
+        assert_eq!(
+            serde_json::to_value(&rates).unwrap()["rates"]["bits"]["type"],
+            "satoshi"
+        );
+    }
+
+    #[test]
+    fn convert_through_btc() {
+        let rates: ExchangeRates = serde_json::from_str(RATES).unwrap();
+
+        assert_eq!(
+            rates.convert(amount("2"), "btc", "usd"),
+            Some(amount("128000"))
+        );
+        assert_eq!(
+            rates.convert(amount("3200"), "usd", "eth"),
+            Some(amount("1"))
+        );
+        assert_eq!(rates.rate("eth", "btc"), Some(amount("0.05")));
+        assert_eq!(rates.convert(amount("1"), "usd", "jpy"), None);
+        assert_eq!(rates.convert(amount("1"), "xau", "usd"), None);
+        assert_eq!(
+            rates.convert(amount("1"), "eur", "usd"),
+            Some(amount("64000") / amount("58000"))
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn convert_overflow_is_none() {
+        let rates: ExchangeRates = serde_json::from_str(RATES).unwrap();
+
+        assert_eq!(rates.convert(Amount::MAX, "eth", "usd"), None);
+    }
 }
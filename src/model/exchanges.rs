@@ -0,0 +1,78 @@
+use crate::model::apimodels::Ticker;
+use crate::model::common::{Amount, ExactAmount};
+use crate::model::identifiers::ExchangeId;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Entry of /exchanges/list
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ExchangeListing {
+    pub id: ExchangeId,
+    pub name: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+/// Active exchange with its trading volume from /exchanges
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Exchange {
+    pub id: ExchangeId,
+    pub name: String,
+    pub year_established: Option<u16>,
+    pub country: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+    pub has_trading_incentive: Option<bool>,
+    /// Trust score from 1 to 10
+    pub trust_score: Option<u8>,
+    pub trust_score_rank: Option<u32>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub trade_volume_24h_btc: Option<Amount>,
+    /// 24h volume with the volume of suspicious pairs (wash trading, zero fees, ...) adjusted
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub trade_volume_24h_btc_normalized: Option<Amount>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+/// Exchange details from /exchanges/{id}, including the top 100 tickers of the exchange
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ExchangeDetail {
+    pub name: String,
+    pub year_established: Option<u16>,
+    pub country: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+    pub facebook_url: Option<String>,
+    pub reddit_url: Option<String>,
+    pub telegram_url: Option<String>,
+    pub slack_url: Option<String>,
+    pub other_url_1: Option<String>,
+    pub other_url_2: Option<String>,
+    pub twitter_handle: Option<String>,
+    pub has_trading_incentive: Option<bool>,
+    pub centralized: Option<bool>,
+    pub public_notice: Option<String>,
+    pub alert_notice: Option<String>,
+    /// Trust score from 1 to 10
+    pub trust_score: Option<u8>,
+    pub trust_score_rank: Option<u32>,
+    /// Number of coins traded on the exchange
+    pub coins: Option<u32>,
+    /// Number of trading pairs on the exchange
+    pub pairs: Option<u32>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub trade_volume_24h_btc: Option<Amount>,
+    #[serde_as(as = "Option<ExactAmount>")]
+    pub trade_volume_24h_btc_normalized: Option<Amount>,
+    pub tickers: Vec<Ticker>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
@@ -1,21 +1,153 @@
-use crate::model::common::AllCurrencies;
+use crate::model::common::{AllCurrencies, Amount, ExactAmount};
+use crate::model::timestamp::{DateTime, UnixSeconds};
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Global {
-    active_cryptocurrencies: i64,
-    upcoming_icos: i64,
-    ongoing_icos: i64,
-    ended_icos: i64,
-    markets: i64,
-    total_market_cap: AllCurrencies,
-    total_volume: AllCurrencies,
-    market_cap_percentage: AllCurrencies,
-    market_cap_change_percentage_24h_usd: f64,
-    updated_at: i64,
+    pub active_cryptocurrencies: i64,
+    pub upcoming_icos: i64,
+    pub ongoing_icos: i64,
+    pub ended_icos: i64,
+    pub markets: i64,
+    /// Total market cap keyed by vs_currency
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub total_market_cap: AllCurrencies,
+    /// Total 24h volume keyed by vs_currency
+    #[serde_as(as = "HashMap<_, Option<ExactAmount>>")]
+    pub total_volume: AllCurrencies,
+    /// Share of the total market cap in percent keyed by coin symbol (btc, eth, ...)
+    #[serde_as(as = "HashMap<_, ExactAmount>")]
+    pub market_cap_percentage: HashMap<String, Amount>,
+    pub market_cap_change_percentage_24h_usd: f64,
+    #[serde_as(as = "UnixSeconds")]
+    pub updated_at: DateTime,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+impl Global {
+    /// The `n` coins with the largest share of the total market cap, largest first
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rustgecko::client::GeckoClient;
+    /// async fn top_three() {
+    ///     let global = GeckoClient::default().global().await.unwrap().data;
+    ///     for (symbol, percentage) in global.dominance(3) {
+    ///         println!("{}: {}%", symbol, percentage);
+    ///     }
+    /// }
+    /// ```
+    pub fn dominance(&self, n: usize) -> Vec<(&str, Amount)> {
+        let mut dominance = self
+            .market_cap_percentage
+            .iter()
+            .map(|(symbol, percentage)| (symbol.as_str(), *percentage))
+            .collect::<Vec<_>>();
+
+        dominance.sort_by(|(a_symbol, a), (b_symbol, b)| {
+            b.partial_cmp(a)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a_symbol.cmp(b_symbol))
+        });
+        dominance.truncate(n);
+        dominance
+    }
+
+    /// The total market cap in a vs_currency, eg. "usd"
+    pub fn total_market_cap_in(&self, currency: &str) -> Option<Amount> {
+        self.total_market_cap.get(currency).copied().flatten()
+    }
+
+    /// The total market cap in a vs_currency without the share of the given coin symbols
+    pub fn market_cap_excluding(&self, currency: &str, symbols: &[&str]) -> Option<Amount> {
+        let total = self.total_market_cap_in(currency)?;
+        let excluded = symbols
+            .iter()
+            .filter_map(|symbol| self.market_cap_percentage.get(*symbol))
+            .copied()
+            .sum::<Amount>();
+
+        Some(total * (Amount::from(100u8) - excluded) / Amount::from(100u8))
+    }
+
+    /// The total market cap in a vs_currency without Bitcoin and Ethereum
+    pub fn market_cap_excluding_btc_eth(&self, currency: &str) -> Option<Amount> {
+        self.market_cap_excluding(currency, &["btc", "eth"])
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct GlobalData {
-    data: Global,
+    pub data: Global,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::model::common::Amount;
+    use crate::model::global::GlobalData;
+
+    const GLOBAL: &str = r#"{
+        "data": {
+            "active_cryptocurrencies": 10000,
+            "upcoming_icos": 0,
+            "ongoing_icos": 49,
+            "ended_icos": 3376,
+            "markets": 900,
+            "total_market_cap": {"usd": 2000000000000, "eur": 1800000000000, "vef": null},
+            "total_volume": {"usd": 80000000000},
+            "market_cap_percentage": {"btc": 50, "eth": 15, "usdt": 5, "bnb": 5},
+            "market_cap_change_percentage_24h_usd": -1.5,
+            "updated_at": 1700000000
+        }
+    }"#;
+
+    fn amount(value: &str) -> Amount {
+        Amount::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn dominance_is_sorted() {
+        let global = serde_json::from_str::<GlobalData>(GLOBAL).unwrap().data;
+
+        assert_eq!(
+            global.dominance(3),
+            vec![
+                ("btc", amount("50")),
+                ("eth", amount("15")),
+                ("bnb", amount("5"))
+            ]
+        );
+        assert_eq!(global.dominance(10).len(), 4);
+    }
+
+    #[test]
+    fn market_cap_helpers() {
+        let global = serde_json::from_str::<GlobalData>(GLOBAL).unwrap().data;
+
+        assert_eq!(
+            global.total_market_cap_in("eur"),
+            Some(amount("1800000000000"))
+        );
+        assert_eq!(global.total_market_cap_in("vef"), None);
+        assert_eq!(
+            global.market_cap_excluding_btc_eth("usd"),
+            Some(amount("700000000000"))
+        );
+        assert_eq!(
+            serde_json::to_value(&global).unwrap()["updated_at"],
+            1700000000
+        );
+    }
 }
@@ -0,0 +1,275 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use tiny_keccak::{Hasher, Keccak};
+
+macro_rules! string_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Self {
+                $name(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                $name::new(id)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name::new(id)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+string_id!(
+    /// Id of a coin, eg. "bitcoin" (refers to /coins/list)
+    CoinId
+);
+
+string_id!(
+    /// Id of an asset platform, eg. "ethereum" or "solana" (refers to /asset_platforms)
+    AssetPlatformId
+);
+
+string_id!(
+    /// Id of a coin category, eg. "layer-1" (refers to /coins/categories/list)
+    CategoryId
+);
+
+string_id!(
+    /// Id of an exchange, eg. "binance" (refers to /exchanges/list)
+    ExchangeId
+);
+
+/// Address format family of a [ContractAddress]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChainFamily {
+    /// Hex encoded 20 byte addresses used by Ethereum and its sidechains/rollups.
+    /// Case only carries the EIP-55 checksum, so these are compared case-insensitively.
+    Evm,
+    /// Every other address format (Solana, Tron, Near, ...). These are case-sensitive and kept verbatim.
+    Other,
+}
+
+impl ChainFamily {
+    /// Detects the family from the format of the address
+    pub fn of(address: &str) -> ChainFamily {
+        match address
+            .strip_prefix("0x")
+            .or_else(|| address.strip_prefix("0X"))
+        {
+            Some(hex) if hex.len() == 40 && hex.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                ChainFamily::Evm
+            }
+            _ => ChainFamily::Other,
+        }
+    }
+}
+
+/// Address of a token contract on an asset platform
+///
+/// The address is normalized when it is created so two addresses of the same contract always compare equal:
+/// EVM addresses are stored in lowercase, all other formats are kept verbatim since they are case-sensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::identifiers::ContractAddress;
+/// let checksummed = ContractAddress::new("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984");
+/// let lowercase = ContractAddress::new("0x1f9840a85d5af5bf1d1762f925bdaddc4201f984");
+/// assert_eq!(checksummed, lowercase);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct ContractAddress(String);
+
+impl ContractAddress {
+    pub fn new(address: impl Into<String>) -> Self {
+        let address = address.into();
+        let trimmed = address.trim();
+
+        match ChainFamily::of(trimmed) {
+            ChainFamily::Evm => ContractAddress(trimmed.to_ascii_lowercase()),
+            ChainFamily::Other => ContractAddress(trimmed.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn chain_family(&self) -> ChainFamily {
+        ChainFamily::of(&self.0)
+    }
+
+    /// The EIP-55 mixed-case checksum encoding of an EVM address, None for all other chain families
+    pub fn to_checksum(&self) -> Option<String> {
+        if self.chain_family() != ChainFamily::Evm {
+            return None;
+        }
+
+        let hex = &self.0[2..];
+        let mut hash = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(hex.as_bytes());
+        keccak.finalize(&mut hash);
+
+        let checksummed = hex
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect::<String>();
+
+        Some(format!("0x{}", checksummed))
+    }
+}
+
+impl<'de> Deserialize<'de> for ContractAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(ContractAddress::new)
+    }
+}
+
+impl Display for ContractAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for ContractAddress {
+    fn from(address: &str) -> Self {
+        ContractAddress::new(address)
+    }
+}
+
+impl From<String> for ContractAddress {
+    fn from(address: String) -> Self {
+        ContractAddress::new(address)
+    }
+}
+
+impl AsRef<str> for ContractAddress {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::model::identifiers::{ChainFamily, CoinId, ContractAddress};
+
+    #[test]
+    fn evm_addresses_are_lowercased() {
+        let address = ContractAddress::new(" 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+
+        assert_eq!(address.chain_family(), ChainFamily::Evm);
+        assert_eq!(
+            address.as_str(),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+    }
+
+    #[test]
+    fn other_addresses_are_kept_verbatim() {
+        let solana = ContractAddress::new("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let sui = ContractAddress::new(
+            "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN",
+        );
+
+        assert_eq!(solana.chain_family(), ChainFamily::Other);
+        assert_eq!(
+            solana.as_str(),
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+        assert_eq!(sui.chain_family(), ChainFamily::Other);
+        assert!(sui.as_str().ends_with("::coin::COIN"));
+        assert_eq!(solana.to_checksum(), None);
+    }
+
+    #[test]
+    fn eip55_checksum() {
+        for expected in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        ] {
+            let address = ContractAddress::new(expected.to_lowercase());
+            assert_eq!(address.to_checksum().as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn deserialized_keys_are_normalized() {
+        let prices: HashMap<ContractAddress, f64> =
+            serde_json::from_str(r#"{"0x1F9840A85D5AF5BF1D1762F925BDADDC4201F984": 5.1}"#).unwrap();
+
+        assert_eq!(
+            prices.get(&ContractAddress::new(
+                "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984"
+            )),
+            Some(&5.1)
+        );
+    }
+
+    #[test]
+    fn ids_lookup_by_str() {
+        let ranks: HashMap<CoinId, u32> = HashMap::from([(CoinId::new("bitcoin"), 1)]);
+
+        assert_eq!(ranks.get("bitcoin"), Some(&1));
+        assert_eq!(CoinId::from("bitcoin"), "bitcoin");
+    }
+}
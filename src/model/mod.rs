@@ -1,7 +1,13 @@
 pub mod apimodels;
+pub mod categories;
 pub mod coins;
 pub mod common;
+pub mod description;
 pub mod exchangerates;
+pub mod exchanges;
 pub mod global;
+pub mod identifiers;
 pub mod queryparams;
 pub mod simple;
+pub mod timeseries;
+pub mod timestamp;
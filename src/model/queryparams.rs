@@ -1,12 +1,20 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
+use crate::endpoint::{Endpoint, Plan};
+use crate::error::Error;
+use crate::model::apimodels::{CoinsMarketItem, Tickers};
+use crate::model::identifiers::{CategoryId, CoinId, ExchangeId};
+
+#[deprecated(note = "use TickerOrder")]
 pub enum TrustOrder {
     TrustScoreDesc,
     TrustScoreAsc,
     VolumeDesc,
 }
 
+#[allow(deprecated)]
 impl fmt::Display for TrustOrder {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -17,6 +25,7 @@ impl fmt::Display for TrustOrder {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MarketOrder {
     MarketCapDesc,
     MarketCapAsc,
@@ -73,6 +82,7 @@ impl fmt::Display for MarketOrder {
     }
 }
 */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PriceChange {
     Hours1,
     Hours24,
@@ -91,7 +101,7 @@ impl Display for PriceChange {
             PriceChange::Days7 => write!(f, "7d"),
             PriceChange::Days14 => write!(f, "14d"),
             PriceChange::Days30 => write!(f, "30d"),
-            PriceChange::Days200 => write!(f, "30d"),
+            PriceChange::Days200 => write!(f, "200d"),
             PriceChange::Years1 => write!(f, "1y"),
         }
     }
@@ -141,9 +151,12 @@ impl Display for PriceChangePercentage {
     }
 }
 
+/// Sort order of the /coins/{id}/tickers and /exchanges/{id}/tickers endpoints. The API defaults to trust_score_desc
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TickerOrder {
     TrustScoreAsc,
     TrustScoreDesc,
+    VolumeAsc,
     VolumeDesc,
 }
 
@@ -152,11 +165,38 @@ impl Display for TickerOrder {
         match self {
             TickerOrder::TrustScoreAsc => write!(f, "trust_score_asc"),
             TickerOrder::TrustScoreDesc => write!(f, "trust_score_desc"),
+            TickerOrder::VolumeAsc => write!(f, "volume_asc"),
             TickerOrder::VolumeDesc => write!(f, "volume_desc"),
         }
     }
 }
 
+/// Sort order of the /coins/categories endpoint. The API defaults to market_cap_desc
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryOrder {
+    MarketCapDesc,
+    MarketCapAsc,
+    NameDesc,
+    NameAsc,
+    MarketCapChange24hDesc,
+    MarketCapChange24hAsc,
+}
+
+impl Display for CategoryOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CategoryOrder::MarketCapDesc => write!(f, "market_cap_desc"),
+            CategoryOrder::MarketCapAsc => write!(f, "market_cap_asc"),
+            CategoryOrder::NameDesc => write!(f, "name_desc"),
+            CategoryOrder::NameAsc => write!(f, "name_asc"),
+            CategoryOrder::MarketCapChange24hDesc => write!(f, "market_cap_change_24h_desc"),
+            CategoryOrder::MarketCapChange24hAsc => write!(f, "market_cap_change_24h_asc"),
+        }
+    }
+}
+
+/// Time range of the /coins/{id}/ohlc endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OhlcDays {
     OneDay,
     SevenDays,
@@ -165,10 +205,688 @@ pub enum OhlcDays {
     NinetyDays,
     OneHundredEightyDays,
     ThreeHundredSixtyFiveDays,
+    Max,
 }
 
 impl Display for OhlcDays {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        todo!()
+        match self {
+            OhlcDays::OneDay => write!(f, "1"),
+            OhlcDays::SevenDays => write!(f, "7"),
+            OhlcDays::FourteenDays => write!(f, "14"),
+            OhlcDays::ThirtyDays => write!(f, "30"),
+            OhlcDays::NinetyDays => write!(f, "90"),
+            OhlcDays::OneHundredEightyDays => write!(f, "180"),
+            OhlcDays::ThreeHundredSixtyFiveDays => write!(f, "365"),
+            OhlcDays::Max => write!(f, "max"),
+        }
+    }
+}
+
+/// Candle size of the /coins/{id}/ohlc endpoint, only available with a paid plan.
+/// Without it the size follows from the days: 30 minutes up to 2 days, 4 hours up to 30 days and 4 days above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OhlcInterval {
+    Daily,
+    Hourly,
+}
+
+impl Display for OhlcInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OhlcInterval::Daily => write!(f, "daily"),
+            OhlcInterval::Hourly => write!(f, "hourly"),
+        }
+    }
+}
+
+impl OhlcInterval {
+    /// Longest time range a single /coins/{id}/ohlc/range request may span with this interval
+    pub fn max_span(&self) -> Duration {
+        match self {
+            OhlcInterval::Daily => Duration::from_secs(180 * 24 * 60 * 60),
+            OhlcInterval::Hourly => Duration::from_secs(31 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Time range of the /coins/{id}/market_chart endpoints, counted back from now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartDays {
+    Days(u32),
+    Max,
+}
+
+impl Display for ChartDays {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartDays::Days(days) => write!(f, "{}", days),
+            ChartDays::Max => write!(f, "max"),
+        }
+    }
+}
+
+/// Fixed spacing of the data points of the /coins/{id}/market_chart endpoints.
+/// Only daily data is available on the public API, the other intervals need a paid plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    Daily,
+    Hourly,
+    FiveMinutes,
+}
+
+impl Interval {
+    /// The plan the interval is available on
+    pub fn plan(&self) -> Plan {
+        match self {
+            Interval::Daily => Plan::Public,
+            Interval::Hourly | Interval::FiveMinutes => Plan::Pro,
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Interval::Daily => write!(f, "daily"),
+            Interval::Hourly => write!(f, "hourly"),
+            Interval::FiveMinutes => write!(f, "5m"),
+        }
+    }
+}
+
+/// Spacing of the data points of the market chart endpoints.
+/// Coingecko derives it from the length of the requested range: 5 minutes up to 1 day, hourly up to 90 days and daily above.
+/// A range is fetched in windows of at most [Granularity::max_span], one request each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    FiveMinutes,
+    Hourly,
+    Daily,
+}
+
+impl Granularity {
+    /// Time between two data points
+    pub fn step(&self) -> Duration {
+        match self {
+            Granularity::FiveMinutes => Duration::from_secs(5 * 60),
+            Granularity::Hourly => Duration::from_secs(60 * 60),
+            Granularity::Daily => Duration::from_secs(24 * 60 * 60),
+        }
+    }
+
+    /// Shortest range a request has to span to get this granularity
+    pub fn min_span(&self) -> Duration {
+        match self {
+            Granularity::FiveMinutes => Duration::ZERO,
+            Granularity::Hourly => Duration::from_secs(2 * 24 * 60 * 60),
+            Granularity::Daily => Duration::from_secs(91 * 24 * 60 * 60),
+        }
+    }
+
+    /// Longest range a request may span to get this granularity, None if there is no limit
+    pub fn max_span(&self) -> Option<Duration> {
+        match self {
+            Granularity::FiveMinutes => Some(Duration::from_secs(24 * 60 * 60)),
+            Granularity::Hourly => Some(Duration::from_secs(90 * 24 * 60 * 60)),
+            Granularity::Daily => None,
+        }
+    }
+}
+
+/// Languages Coingecko translates names and descriptions into, identified by their language code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Ar,
+    Bg,
+    Cs,
+    Da,
+    De,
+    El,
+    En,
+    Es,
+    Fi,
+    Fr,
+    He,
+    Hi,
+    Hr,
+    Hu,
+    Id,
+    It,
+    Ja,
+    Ko,
+    Lt,
+    Nl,
+    No,
+    Pl,
+    Pt,
+    Ro,
+    Ru,
+    Sk,
+    Sl,
+    Sv,
+    Th,
+    Tr,
+    Uk,
+    Vi,
+    Zh,
+    ZhTw,
+}
+
+impl Language {
+    /// The code Coingecko uses for the language, eg. "de" or "zh-tw"
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Ar => "ar",
+            Language::Bg => "bg",
+            Language::Cs => "cs",
+            Language::Da => "da",
+            Language::De => "de",
+            Language::El => "el",
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Fi => "fi",
+            Language::Fr => "fr",
+            Language::He => "he",
+            Language::Hi => "hi",
+            Language::Hr => "hr",
+            Language::Hu => "hu",
+            Language::Id => "id",
+            Language::It => "it",
+            Language::Ja => "ja",
+            Language::Ko => "ko",
+            Language::Lt => "lt",
+            Language::Nl => "nl",
+            Language::No => "no",
+            Language::Pl => "pl",
+            Language::Pt => "pt",
+            Language::Ro => "ro",
+            Language::Ru => "ru",
+            Language::Sk => "sk",
+            Language::Sl => "sl",
+            Language::Sv => "sv",
+            Language::Th => "th",
+            Language::Tr => "tr",
+            Language::Uk => "uk",
+            Language::Vi => "vi",
+            Language::Zh => "zh",
+            Language::ZhTw => "zh-tw",
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Which localized names and descriptions to request from /coins/{id} and /coins/{id}/history
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Localize {
+    /// Only english
+    None,
+    /// Every language Coingecko has
+    #[default]
+    All,
+    /// Only the given languages and english, the others are dropped from the response
+    Only(Vec<Language>),
+}
+
+impl Localize {
+    /// True if the translation for the language code is kept
+    pub fn includes(&self, code: &str) -> bool {
+        match self {
+            Localize::None => code == Language::En.code(),
+            Localize::All => true,
+            Localize::Only(languages) => {
+                code == Language::En.code() || languages.iter().any(|lang| lang.code() == code)
+            }
+        }
+    }
+}
+
+impl Display for Localize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Localize::None => write!(f, "false"),
+            Localize::All | Localize::Only(_) => write!(f, "true"),
+        }
+    }
+}
+
+/// Decimal places of the returned price values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Full precision as stored by Coingecko
+    Full,
+    /// Round to the given number of decimal places (0 - 18), more are rejected with [Error::InvalidParams]
+    Decimals(u8),
+}
+
+impl Precision {
+    /// Most decimal places the API rounds to
+    pub const MAX_DECIMALS: u8 = 18;
+
+    /// Fails with [Error::InvalidParams] for more than [Precision::MAX_DECIMALS] decimal places
+    pub(crate) fn check(&self, endpoint: &str) -> Result<(), Error> {
+        match self {
+            Precision::Decimals(places) if *places > Precision::MAX_DECIMALS => {
+                Err(Error::InvalidParams {
+                    endpoint: endpoint.to_string(),
+                    message: format!(
+                        "precision: {} decimal places, at most {} are supported",
+                        places,
+                        Precision::MAX_DECIMALS
+                    ),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for Precision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Precision::Full => write!(f, "full"),
+            Precision::Decimals(places) => write!(f, "{}", places),
+        }
+    }
+}
+
+/// Query parameters of the /coins/markets endpoint
+///
+/// Only `vs_currency` is required, every other parameter is left out of the query
+/// when it is not set so the API defaults apply.
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::queryparams::{CoinsMarketsRequest, MarketOrder, PriceChange, Precision};
+/// let request = CoinsMarketsRequest::new("usd")
+///     .ids(["bitcoin", "ethereum"])
+///     .order(MarketOrder::VolumeDesc)
+///     .per_page(250)
+///     .price_change_percentage(&[PriceChange::Hours1, PriceChange::Days7])
+///     .precision(Precision::Full);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinsMarketsRequest {
+    pub vs_currency: String,
+    pub ids: Vec<String>,
+    pub category: Option<CategoryId>,
+    pub order: Option<MarketOrder>,
+    pub per_page: Option<u16>,
+    pub page: Option<u32>,
+    pub sparkline: bool,
+    pub price_change_percentage: Vec<PriceChange>,
+    pub locale: Option<Language>,
+    pub precision: Option<Precision>,
+}
+
+impl CoinsMarketsRequest {
+    /// Creates a request for the market data in the target currency (usd, eur, jpy, etc.)
+    pub fn new(vs_currency: impl Into<String>) -> Self {
+        CoinsMarketsRequest {
+            vs_currency: vs_currency.into(),
+            ids: Vec::new(),
+            category: None,
+            order: None,
+            per_page: None,
+            page: None,
+            sparkline: false,
+            price_change_percentage: Vec::new(),
+            locale: None,
+            precision: None,
+        }
+    }
+
+    /// Only return the coins with the given ids (refers to /coins/list)
+    pub fn ids<I, S>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ids = ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return the coins of the given category (refers to /coins/categories/list)
+    pub fn category(mut self, category: impl Into<CategoryId>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    /// Sort results by field. The API defaults to market_cap_desc
+    pub fn order(mut self, order: MarketOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Total results per page, valid values are 1 - 250. The API defaults to 100
+    pub fn per_page(mut self, per_page: u16) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Page through results. The API defaults to 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Include sparkline 7 days data
+    pub fn sparkline(mut self, sparkline: bool) -> Self {
+        self.sparkline = sparkline;
+        self
+    }
+
+    /// Include price change percentage for the given timeframes
+    pub fn price_change_percentage(mut self, timeframes: &[PriceChange]) -> Self {
+        self.price_change_percentage = timeframes.to_vec();
+        self
+    }
+
+    /// Language of the localized fields. The API defaults to en
+    pub fn locale(mut self, locale: Language) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Decimal places of the returned price values
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// The query parameters in the order they are sent to the API
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![("vs_currency", self.vs_currency.clone())];
+
+        if !self.ids.is_empty() {
+            params.push(("ids", self.ids.join(",")));
+        }
+        if let Some(category) = &self.category {
+            params.push(("category", category.to_string()));
+        }
+        if let Some(order) = self.order {
+            params.push(("order", order.to_string()));
+        }
+        if let Some(per_page) = self.per_page {
+            params.push(("per_page", per_page.to_string()));
+        }
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        params.push(("sparkline", self.sparkline.to_string()));
+        if !self.price_change_percentage.is_empty() {
+            let timeframes = self
+                .price_change_percentage
+                .iter()
+                .map(|ele| ele.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            params.push(("price_change_percentage", timeframes));
+        }
+        if let Some(locale) = self.locale {
+            params.push(("locale", locale.to_string()));
+        }
+        if let Some(precision) = self.precision {
+            params.push(("precision", precision.to_string()));
+        }
+        params
+    }
+}
+
+/// Request of an endpoint that returns its results in pages
+pub trait Paged: Endpoint + Clone {
+    /// Number of results the API returns per page, a shorter page is the last one
+    const PAGE_SIZE: usize;
+
+    /// The request of the page after this one
+    fn next_page(&self) -> Self;
+}
+
+/// Parameters of the /coins/{id}/tickers endpoint
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::queryparams::{CoinTickersRequest, TickerOrder};
+///
+/// let request = CoinTickersRequest::new("bitcoin")
+///     .exchange_ids(["binance", "kraken"])
+///     .order(TickerOrder::VolumeDesc)
+///     .depth(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinTickersRequest {
+    pub id: CoinId,
+    pub exchange_ids: Vec<ExchangeId>,
+    pub include_exchange_logo: bool,
+    pub page: Option<u32>,
+    pub order: Option<TickerOrder>,
+    pub depth: bool,
+}
+
+impl CoinTickersRequest {
+    /// Creates a request for the tickers of the coin (refers to /coins/list)
+    pub fn new(id: impl Into<CoinId>) -> Self {
+        CoinTickersRequest {
+            id: id.into(),
+            exchange_ids: Vec::new(),
+            include_exchange_logo: false,
+            page: None,
+            order: None,
+            depth: false,
+        }
+    }
+
+    /// Only return the tickers of the given exchanges (refers to /exchanges/list)
+    pub fn exchange_ids<I, S>(mut self, exchange_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ExchangeId>,
+    {
+        self.exchange_ids = exchange_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Include the logo of the exchange in the market of each ticker
+    pub fn include_exchange_logo(mut self, include_exchange_logo: bool) -> Self {
+        self.include_exchange_logo = include_exchange_logo;
+        self
+    }
+
+    /// Page through results, 100 tickers per page. The API defaults to 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sort results by field. The API defaults to trust_score_desc
+    pub fn order(mut self, order: TickerOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Include the 2% orderbook depth, cost_to_move_up_usd and cost_to_move_down_usd
+    pub fn depth(mut self, depth: bool) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// The query parameters in the order they are sent to the API
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if !self.exchange_ids.is_empty() {
+            params.push(("exchange_ids", self.exchange_ids.join(",")));
+        }
+        params.push((
+            "include_exchange_logo",
+            self.include_exchange_logo.to_string(),
+        ));
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(order) = self.order {
+            params.push(("order", order.to_string()));
+        }
+        params.push(("depth", self.depth.to_string()));
+        params
+    }
+}
+
+impl Paged for CoinTickersRequest {
+    const PAGE_SIZE: usize = 100;
+
+    fn next_page(&self) -> Self {
+        self.clone().page(self.page.unwrap_or(1) + 1)
+    }
+}
+
+impl Endpoint for CoinTickersRequest {
+    type Response = Tickers;
+
+    fn path(&self) -> String {
+        format!("/coins/{}/tickers", self.id)
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        self.query_params()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
+    }
+}
+
+/// Parameters of the /exchanges/{id}/tickers endpoint
+///
+/// # Examples
+///
+/// ```rust
+/// use rustgecko::model::queryparams::{ExchangeTickersRequest, TickerOrder};
+///
+/// let request = ExchangeTickersRequest::new("binance")
+///     .coin_ids(["bitcoin", "ethereum"])
+///     .order(TickerOrder::VolumeDesc)
+///     .depth(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeTickersRequest {
+    pub id: ExchangeId,
+    pub coin_ids: Vec<CoinId>,
+    pub include_exchange_logo: bool,
+    pub page: Option<u32>,
+    pub order: Option<TickerOrder>,
+    pub depth: bool,
+}
+
+impl ExchangeTickersRequest {
+    /// Creates a request for the tickers of the exchange (refers to /exchanges/list)
+    pub fn new(id: impl Into<ExchangeId>) -> Self {
+        ExchangeTickersRequest {
+            id: id.into(),
+            coin_ids: Vec::new(),
+            include_exchange_logo: false,
+            page: None,
+            order: None,
+            depth: false,
+        }
+    }
+
+    /// Only return the tickers of the given coins (refers to /coins/list)
+    pub fn coin_ids<I, S>(mut self, coin_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<CoinId>,
+    {
+        self.coin_ids = coin_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Include the logo of the exchange in the market of each ticker
+    pub fn include_exchange_logo(mut self, include_exchange_logo: bool) -> Self {
+        self.include_exchange_logo = include_exchange_logo;
+        self
+    }
+
+    /// Page through results, 100 tickers per page. The API defaults to 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sort results by field. The API defaults to trust_score_desc
+    pub fn order(mut self, order: TickerOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Include the 2% orderbook depth, cost_to_move_up_usd and cost_to_move_down_usd
+    pub fn depth(mut self, depth: bool) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// The query parameters in the order they are sent to the API
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if !self.coin_ids.is_empty() {
+            params.push(("coin_ids", self.coin_ids.join(",")));
+        }
+        params.push((
+            "include_exchange_logo",
+            self.include_exchange_logo.to_string(),
+        ));
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(order) = self.order {
+            params.push(("order", order.to_string()));
+        }
+        params.push(("depth", self.depth.to_string()));
+        params
+    }
+}
+
+impl Paged for ExchangeTickersRequest {
+    const PAGE_SIZE: usize = 100;
+
+    fn next_page(&self) -> Self {
+        self.clone().page(self.page.unwrap_or(1) + 1)
+    }
+}
+
+impl Endpoint for ExchangeTickersRequest {
+    type Response = Tickers;
+
+    fn path(&self) -> String {
+        format!("/exchanges/{}/tickers", self.id)
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        self.query_params()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
+    }
+}
+
+impl Endpoint for CoinsMarketsRequest {
+    type Response = Vec<CoinsMarketItem>;
+
+    fn path(&self) -> String {
+        "/coins/markets".to_string()
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        self.query_params()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
     }
 }
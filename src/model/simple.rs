@@ -1,15 +1,19 @@
+use crate::model::identifiers::{AssetPlatformId, CoinId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub use crate::model::identifiers::ContractAddress;
+
 ///
 /// The Symbol is provided in lowercase letters
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CoinListing {
-    pub id: String,
+    pub id: CoinId,
     pub symbol: String,
     pub name: String,
-    pub platforms: Option<HashMap<String, Option<String>>>,
+    pub platforms: Option<HashMap<AssetPlatformId, Option<ContractAddress>>>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: crate::model::common::Extra,
 }
-
-pub type ContractAddress = String;
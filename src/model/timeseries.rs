@@ -0,0 +1,382 @@
+//! Row aligned view of a [MarketChart].
+//!
+//! Coingecko sends the prices, market caps and volumes of a chart as three separate arrays.
+//! A [TimeSeries] joins them by timestamp into [Row]s sorted by time.
+
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+use crate::model::apimodels::{Candle, MarketChart};
+use crate::model::common::Amount;
+use crate::model::timestamp::{backend, DateTime};
+
+/// Values of a chart at one point in time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub time: DateTime,
+    pub price: Option<Amount>,
+    pub market_cap: Option<Amount>,
+    pub volume: Option<Amount>,
+}
+
+/// How the rows inside an interval are combined by [TimeSeries::resample]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The last value of the interval
+    Last,
+    /// The mean of all values of the interval
+    Mean,
+    /// Open, high, low and close of the prices of the interval
+    Ohlc,
+}
+
+/// Result of [TimeSeries::resample], candles for [Aggregation::Ohlc] and rows for every other aggregation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resampled {
+    Rows(TimeSeries),
+    Candles(Vec<Candle>),
+}
+
+impl Resampled {
+    pub fn into_rows(self) -> Option<TimeSeries> {
+        match self {
+            Resampled::Rows(series) => Some(series),
+            Resampled::Candles(_) => None,
+        }
+    }
+
+    pub fn into_candles(self) -> Option<Vec<Candle>> {
+        match self {
+            Resampled::Candles(candles) => Some(candles),
+            Resampled::Rows(_) => None,
+        }
+    }
+}
+
+/// Rows of a [MarketChart] aligned by timestamp and sorted by time
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rustgecko::model::apimodels::{Candle, MarketChart};
+/// use rustgecko::model::timeseries::TimeSeries;
+/// fn daily_candles(chart: &MarketChart) -> Vec<Candle> {
+///     TimeSeries::from(chart).to_candles(Duration::from_secs(24 * 60 * 60))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimeSeries {
+    rows: Vec<Row>,
+    duplicates: Vec<DateTime>,
+}
+
+impl TimeSeries {
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Row> {
+        self.rows.iter()
+    }
+
+    /// Timestamps that appeared more than once in one of the arrays of the chart.
+    /// The series keeps the last value sent for them.
+    pub fn duplicates(&self) -> &[DateTime] {
+        &self.duplicates
+    }
+
+    /// Pairs of consecutive rows that are further apart than `max_step`
+    pub fn gaps(&self, max_step: Duration) -> Vec<(DateTime, DateTime)> {
+        let max_step = max_step.as_millis() as i64;
+
+        self.rows
+            .windows(2)
+            .filter(|pair| millis(&pair[1].time) - millis(&pair[0].time) > max_step)
+            .map(|pair| (pair[0].time, pair[1].time))
+            .collect()
+    }
+
+    /// The rows from `from` (inclusive) to `to` (exclusive)
+    pub fn range(&self, from: DateTime, to: DateTime) -> TimeSeries {
+        TimeSeries {
+            rows: self
+                .rows
+                .iter()
+                .filter(|row| row.time >= from && row.time < to)
+                .cloned()
+                .collect(),
+            duplicates: self
+                .duplicates
+                .iter()
+                .filter(|time| **time >= from && **time < to)
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Combines the rows into fixed intervals aligned to the unix epoch, empty intervals are left out.
+    /// Each resulting row is stamped with the start of its interval,
+    /// [Aggregation::Ohlc] returns the candles of [TimeSeries::to_candles] instead.
+    pub fn resample(&self, interval: Duration, aggregation: Aggregation) -> Resampled {
+        if aggregation == Aggregation::Ohlc {
+            return Resampled::Candles(self.to_candles(interval));
+        }
+
+        let rows = self
+            .buckets(interval)
+            .into_iter()
+            .filter_map(|(start, rows)| {
+                Some(Row {
+                    time: backend::from_unix_millis(start).ok()?,
+                    price: aggregate(rows.iter().map(|row| row.price), aggregation),
+                    market_cap: aggregate(rows.iter().map(|row| row.market_cap), aggregation),
+                    volume: aggregate(rows.iter().map(|row| row.volume), aggregation),
+                })
+            })
+            .collect();
+
+        Resampled::Rows(TimeSeries {
+            rows,
+            duplicates: Vec::new(),
+        })
+    }
+
+    /// Builds candles from the prices in fixed intervals aligned to the unix epoch.
+    /// Like the /coins/{id}/ohlc endpoint each candle is stamped with the end of its interval,
+    /// intervals without a price are left out.
+    pub fn to_candles(&self, interval: Duration) -> Vec<Candle> {
+        let step = step(interval);
+
+        self.buckets(interval)
+            .into_iter()
+            .filter_map(|(start, rows)| {
+                let prices = rows.iter().filter_map(|row| row.price).collect::<Vec<_>>();
+                let open = *prices.first()?;
+                let close = *prices.last()?;
+                let (high, low) = prices.iter().fold((open, open), |(high, low), price| {
+                    (
+                        if *price > high { *price } else { high },
+                        if *price < low { *price } else { low },
+                    )
+                });
+
+                Some(Candle {
+                    time: backend::from_unix_millis(start + step).ok()?,
+                    open,
+                    high,
+                    low,
+                    close,
+                })
+            })
+            .collect()
+    }
+
+    fn buckets(&self, interval: Duration) -> BTreeMap<i64, Vec<&Row>> {
+        let step = step(interval);
+        let mut buckets: BTreeMap<i64, Vec<&Row>> = BTreeMap::new();
+
+        for row in &self.rows {
+            let start = millis(&row.time).div_euclid(step) * step;
+            buckets.entry(start).or_default().push(row);
+        }
+
+        buckets
+    }
+}
+
+impl From<&MarketChart> for TimeSeries {
+    fn from(chart: &MarketChart) -> Self {
+        let mut rows: BTreeMap<i64, Row> = BTreeMap::new();
+        let mut duplicates = Vec::new();
+
+        let columns: [(&[ChartPoint], Column); 3] = [
+            (&chart.prices, |row| &mut row.price),
+            (&chart.market_caps, |row| &mut row.market_cap),
+            (&chart.total_volumes, |row| &mut row.volume),
+        ];
+
+        for (values, column) in columns {
+            let mut seen = HashSet::with_capacity(values.len());
+
+            for (time, value) in values {
+                let Ok(datetime) = backend::from_unix_millis(*time) else {
+                    continue;
+                };
+                if !seen.insert(*time) {
+                    duplicates.push(datetime);
+                }
+
+                let row = rows.entry(*time).or_insert_with(|| Row {
+                    time: datetime,
+                    price: None,
+                    market_cap: None,
+                    volume: None,
+                });
+                *column(row) = *value;
+            }
+        }
+
+        duplicates.sort();
+        duplicates.dedup();
+
+        TimeSeries {
+            rows: rows.into_values().collect(),
+            duplicates,
+        }
+    }
+}
+
+impl From<MarketChart> for TimeSeries {
+    fn from(chart: MarketChart) -> Self {
+        TimeSeries::from(&chart)
+    }
+}
+
+impl IntoIterator for TimeSeries {
+    type Item = Row;
+    type IntoIter = std::vec::IntoIter<Row>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TimeSeries {
+    type Item = &'a Row;
+    type IntoIter = std::slice::Iter<'a, Row>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+type ChartPoint = (i64, Option<Amount>);
+type Column = fn(&mut Row) -> &mut Option<Amount>;
+
+fn millis(time: &DateTime) -> i64 {
+    backend::unix_millis(time)
+}
+
+fn step(interval: Duration) -> i64 {
+    (interval.as_millis() as i64).max(1)
+}
+
+fn aggregate(
+    values: impl Iterator<Item = Option<Amount>>,
+    aggregation: Aggregation,
+) -> Option<Amount> {
+    let values = values.flatten().collect::<Vec<_>>();
+
+    match aggregation {
+        Aggregation::Last | Aggregation::Ohlc => values.last().copied(),
+        Aggregation::Mean if values.is_empty() => None,
+        Aggregation::Mean => {
+            Some(values.iter().copied().sum::<Amount>() / Amount::from(values.len() as u32))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use crate::model::apimodels::MarketChart;
+    use crate::model::common::Amount;
+    use crate::model::timeseries::{Aggregation, Resampled, TimeSeries};
+    use crate::model::timestamp::backend;
+
+    const HOUR: i64 = 60 * 60 * 1000;
+
+    fn amount(value: &str) -> Amount {
+        Amount::from_str(value).unwrap()
+    }
+
+    fn series() -> TimeSeries {
+        let chart: MarketChart = serde_json::from_value(serde_json::json!({
+            "prices": [[0, 10], [HOUR, 14], [HOUR, 12], [2 * HOUR, 8], [5 * HOUR, 20]],
+            "market_caps": [[0, 100], [HOUR, 120], [2 * HOUR, 80], [5 * HOUR, null]],
+            "total_volumes": [[0, 1], [2 * HOUR, 3], [5 * HOUR, 5]]
+        }))
+        .unwrap();
+
+        TimeSeries::from(chart)
+    }
+
+    #[test]
+    fn rows_are_aligned_by_timestamp() {
+        let series = series();
+
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.rows()[1].price, Some(amount("12")));
+        assert_eq!(series.rows()[1].market_cap, Some(amount("120")));
+        assert_eq!(series.rows()[1].volume, None);
+        assert_eq!(series.rows()[3].market_cap, None);
+        assert_eq!(
+            series.duplicates(),
+            &[backend::from_unix_millis(HOUR).unwrap()]
+        );
+    }
+
+    #[test]
+    fn gaps_and_range() {
+        let series = series();
+        let at = |hours: i64| backend::from_unix_millis(hours * HOUR).unwrap();
+
+        assert_eq!(
+            series.gaps(Duration::from_secs(60 * 60)),
+            vec![(at(2), at(5))]
+        );
+        assert_eq!(series.range(at(1), at(5)).len(), 2);
+    }
+
+    #[test]
+    fn resample_and_candles() {
+        let series = series();
+        let three_hours = Duration::from_secs(3 * 60 * 60);
+
+        let mean = series
+            .resample(three_hours, Aggregation::Mean)
+            .into_rows()
+            .unwrap();
+        assert_eq!(mean.len(), 2);
+        assert_eq!(mean.rows()[0].price, Some(amount("10")));
+        assert_eq!(mean.rows()[0].volume, Some(amount("2")));
+
+        let last = series
+            .resample(three_hours, Aggregation::Last)
+            .into_rows()
+            .unwrap();
+        assert_eq!(last.rows()[0].market_cap, Some(amount("80")));
+        assert_eq!(last.rows()[1].market_cap, None);
+
+        let candles = series.to_candles(three_hours);
+        assert_eq!(
+            series.resample(three_hours, Aggregation::Ohlc),
+            Resampled::Candles(candles.clone())
+        );
+        assert_eq!(candles.len(), 2);
+        assert_eq!(
+            (
+                candles[0].open,
+                candles[0].high,
+                candles[0].low,
+                candles[0].close
+            ),
+            (amount("10"), amount("12"), amount("8"), amount("8"))
+        );
+        assert_eq!(
+            candles[0].time,
+            backend::from_unix_millis(3 * HOUR).unwrap()
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! Date and time types used by the models.
+//!
+//! By default timestamps are parsed into [time::OffsetDateTime] and [time::Date].
+//! With the `chrono` feature enabled [chrono::DateTime] in UTC and [chrono::NaiveDate] are used instead.
+//!
+//! The unit structs in this module are [serde_with] adapters that describe how Coingecko
+//! encodes a timestamp, i.e. `#[serde_as(as = "Option<Rfc3339>")]`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Point in time, always in UTC as returned by Coingecko
+#[cfg(not(feature = "chrono"))]
+pub type DateTime = time::OffsetDateTime;
+/// Point in time, always in UTC as returned by Coingecko
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// Calendar date without a time of day
+#[cfg(not(feature = "chrono"))]
+pub type Date = time::Date;
+/// Calendar date without a time of day
+#[cfg(feature = "chrono")]
+pub type Date = chrono::NaiveDate;
+
+/// Timestamps encoded as RFC 3339 strings, eg. "2021-11-10T14:24:11.849Z"
+pub struct Rfc3339;
+
+/// Timestamps encoded as unix time in seconds, eg. 1672531200
+pub struct UnixSeconds;
+
+/// Timestamps encoded as unix time in milliseconds, eg. 1672531200000
+pub struct UnixMillis;
+
+/// Dates encoded as "yyyy-mm-dd", eg. "2009-01-03"
+pub struct IsoDate;
+
+#[cfg(not(feature = "chrono"))]
+pub(crate) mod backend {
+    use time::format_description::well_known;
+    use time::format_description::FormatItem;
+    use time::macros::format_description;
+
+    use super::{Date, DateTime};
+
+    const ISO_DATE_FORMAT: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+    pub fn format_rfc3339(value: &DateTime) -> Result<String, String> {
+        value
+            .format(&well_known::Rfc3339)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn parse_rfc3339(value: &str) -> Result<DateTime, String> {
+        DateTime::parse(value, &well_known::Rfc3339)
+            .map(|datetime| datetime.to_offset(time::UtcOffset::UTC))
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn unix_seconds(value: &DateTime) -> i64 {
+        value.unix_timestamp()
+    }
+
+    pub fn from_unix_seconds(seconds: i64) -> Result<DateTime, String> {
+        DateTime::from_unix_timestamp(seconds).map_err(|err| err.to_string())
+    }
+
+    pub fn unix_millis(value: &DateTime) -> i64 {
+        (value.unix_timestamp_nanos() / 1_000_000) as i64
+    }
+
+    pub fn from_unix_millis(millis: i64) -> Result<DateTime, String> {
+        DateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn format_date(value: &Date) -> Result<String, String> {
+        value.format(ISO_DATE_FORMAT).map_err(|err| err.to_string())
+    }
+
+    pub fn parse_date(value: &str) -> Result<Date, String> {
+        Date::parse(value, ISO_DATE_FORMAT).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) mod backend {
+    use chrono::{SecondsFormat, Utc};
+
+    use super::{Date, DateTime};
+
+    const ISO_DATE_FORMAT: &str = "%Y-%m-%d";
+
+    pub fn format_rfc3339(value: &DateTime) -> Result<String, String> {
+        Ok(value.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    pub fn parse_rfc3339(value: &str) -> Result<DateTime, String> {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|datetime| datetime.with_timezone(&Utc))
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn unix_seconds(value: &DateTime) -> i64 {
+        value.timestamp()
+    }
+
+    pub fn from_unix_seconds(seconds: i64) -> Result<DateTime, String> {
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| format!("unix timestamp {} is out of range", seconds))
+    }
+
+    pub fn unix_millis(value: &DateTime) -> i64 {
+        value.timestamp_millis()
+    }
+
+    pub fn from_unix_millis(millis: i64) -> Result<DateTime, String> {
+        DateTime::from_timestamp_millis(millis)
+            .ok_or_else(|| format!("unix timestamp {}ms is out of range", millis))
+    }
+
+    pub fn format_date(value: &Date) -> Result<String, String> {
+        Ok(value.format(ISO_DATE_FORMAT).to_string())
+    }
+
+    pub fn parse_date(value: &str) -> Result<Date, String> {
+        Date::parse_from_str(value, ISO_DATE_FORMAT).map_err(|err| err.to_string())
+    }
+}
+
+impl SerializeAs<DateTime> for Rfc3339 {
+    fn serialize_as<S: Serializer>(source: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        backend::format_rfc3339(source)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, DateTime> for Rfc3339 {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        backend::parse_rfc3339(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl SerializeAs<DateTime> for UnixSeconds {
+    fn serialize_as<S: Serializer>(source: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        backend::unix_seconds(source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, DateTime> for UnixSeconds {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        let value = i64::deserialize(deserializer)?;
+        backend::from_unix_seconds(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl SerializeAs<DateTime> for UnixMillis {
+    fn serialize_as<S: Serializer>(source: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        backend::unix_millis(source).serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, DateTime> for UnixMillis {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        let value = i64::deserialize(deserializer)?;
+        backend::from_unix_millis(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl SerializeAs<Date> for IsoDate {
+    fn serialize_as<S: Serializer>(source: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        backend::format_date(source)
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Date> for IsoDate {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        backend::parse_date(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+    use serde_with::serde_as;
+
+    use super::{backend, Date, DateTime, IsoDate, Rfc3339, UnixMillis, UnixSeconds};
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Timestamps {
+        #[serde_as(as = "Rfc3339")]
+        rfc3339: DateTime,
+        #[serde_as(as = "Option<Rfc3339>")]
+        offset: Option<DateTime>,
+        #[serde_as(as = "UnixSeconds")]
+        unix: DateTime,
+        #[serde_as(as = "UnixMillis")]
+        millis: DateTime,
+        #[serde_as(as = "Option<IsoDate>")]
+        date: Option<Date>,
+    }
+
+    #[test]
+    fn parses_coingecko_formats() {
+        let json = r#"{
+            "rfc3339": "2021-11-10T14:24:11.849Z",
+            "offset": "2021-11-10T16:24:11+02:00",
+            "unix": 1636554251,
+            "millis": 1636554251849,
+            "date": "2009-01-03"
+        }"#;
+        let parsed: Timestamps = serde_json::from_str(json).unwrap();
+
+        assert_eq!(backend::unix_seconds(&parsed.rfc3339), 1636554251);
+        assert_eq!(parsed.offset, Some(parsed.unix));
+        assert_eq!(parsed.millis, parsed.rfc3339);
+        assert_eq!(
+            parsed.date,
+            Some(backend::parse_date("2009-01-03").unwrap())
+        );
+
+        let serialized = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Timestamps>(&serialized).unwrap(),
+            parsed
+        );
+    }
+
+    #[test]
+    fn missing_optional_timestamps_are_none() {
+        let json = r#"{"rfc3339": "2021-11-10T14:24:11Z", "offset": null, "unix": 0, "millis": 0}"#;
+        let parsed: Timestamps = serde_json::from_str(json).unwrap();
+
+        assert_eq!(parsed.offset, None);
+        assert_eq!(parsed.date, None);
+    }
+}
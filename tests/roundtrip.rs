@@ -0,0 +1,165 @@
+//! Every model has to survive a serialize/deserialize round-trip through serde_json without losing data.
+//! The fixtures in `tests/fixtures` are recorded responses of the endpoints, one file per endpoint.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use rustgecko::drift::DriftReport;
+use rustgecko::model::apimodels::{
+    AssetPlatform, Candle, CoinHistoryItem, CoinsMarketItem, MarketChart, Tickers,
+};
+use rustgecko::model::categories::{Category, CategoryListing};
+use rustgecko::model::coins::CoinsItem;
+use rustgecko::model::common::{Ping, Price};
+use rustgecko::model::exchangerates::ExchangeRates;
+use rustgecko::model::exchanges::{Exchange, ExchangeDetail, ExchangeListing};
+use rustgecko::model::global::GlobalData;
+use rustgecko::model::simple::{CoinListing, ContractAddress};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+fn round_trip<T: DeserializeOwned + Serialize + PartialEq + Debug>(fixture: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture);
+    let response: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+    let report = DriftReport::detect::<T>(fixture, &response);
+    assert!(report.is_empty(), "{}", report);
+
+    let model: T = serde_json::from_value(response.clone()).unwrap();
+    let serialized = serde_json::to_string(&model).unwrap();
+    let deserialized: T = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(deserialized, model, "{} changed in the round-trip", fixture);
+    assert_eq!(
+        normalize(serde_json::to_value(&model).unwrap()),
+        normalize(response),
+        "{} does not serialize back to the fixture",
+        fixture
+    );
+}
+
+/// Normalizes the representations serde may choose differently from Coingecko without losing data:
+/// numbers are compared by value (`1` equals `1.0`), RFC 3339 timestamps by the instant they denote
+/// (`2024-04-20T10:12:49+00:00` equals `2024-04-20T10:12:49Z`) and a missing field equals `null`
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Number(number) => number
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map_or(Value::Number(number), Value::Number),
+        Value::String(text) => match OffsetDateTime::parse(&text, &Rfc3339) {
+            Ok(time) => Value::String(time.to_offset(UtcOffset::UTC).format(&Rfc3339).unwrap()),
+            Err(_) => Value::String(text),
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, normalize(value)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+#[test]
+fn ping() {
+    round_trip::<Ping>("ping.json");
+}
+
+#[test]
+fn simple_supported_vs_currencies() {
+    round_trip::<Vec<String>>("simple_supported_vs_currencies.json");
+}
+
+#[test]
+fn simple_price() {
+    round_trip::<HashMap<String, Price>>("simple_price.json");
+}
+
+#[test]
+fn simple_token_price() {
+    round_trip::<HashMap<ContractAddress, Price>>("simple_token_price_ethereum.json");
+}
+
+#[test]
+fn coins_list() {
+    round_trip::<Vec<CoinListing>>("coins_list.json");
+}
+
+#[test]
+fn coins_markets() {
+    round_trip::<Vec<CoinsMarketItem>>("coins_markets.json");
+}
+
+#[test]
+fn coins() {
+    round_trip::<CoinsItem>("coins_bitcoin.json");
+}
+
+#[test]
+fn coins_tickers() {
+    round_trip::<Tickers>("coins_bitcoin_tickers.json");
+}
+
+#[test]
+fn coins_history() {
+    round_trip::<CoinHistoryItem>("coins_bitcoin_history.json");
+}
+
+#[test]
+fn coins_market_chart() {
+    round_trip::<MarketChart>("coins_bitcoin_market_chart.json");
+}
+
+#[test]
+fn coins_ohlc() {
+    round_trip::<Vec<Candle>>("coins_bitcoin_ohlc.json");
+}
+
+#[test]
+fn asset_platforms() {
+    round_trip::<Vec<AssetPlatform>>("asset_platforms.json");
+}
+
+#[test]
+fn exchange_rates() {
+    round_trip::<ExchangeRates>("exchange_rates.json");
+}
+
+#[test]
+fn global() {
+    round_trip::<GlobalData>("global.json");
+}
+
+#[test]
+fn categories_list() {
+    round_trip::<Vec<CategoryListing>>("categories_list.json");
+}
+
+#[test]
+fn categories() {
+    round_trip::<Vec<Category>>("categories.json");
+}
+
+#[test]
+fn exchanges() {
+    round_trip::<Vec<Exchange>>("exchanges.json");
+}
+
+#[test]
+fn exchanges_list() {
+    round_trip::<Vec<ExchangeListing>>("exchanges_list.json");
+}
+
+#[test]
+fn exchange_detail() {
+    round_trip::<ExchangeDetail>("exchanges_binance.json");
+}